@@ -0,0 +1,274 @@
+//! `#[derive(AocParse)]`: parsers for puzzle lines generated from a format string.
+//!
+//! A struct takes its format from `#[aoc(format = "...")]` on the type, an enum from
+//! the same attribute on each variant. In the format, `{name}` is a named field, `{}`
+//! is the next unnamed field, `{_}` is text to skip, and `{{`/`}}` are literal braces.
+//! Every field's type must implement `aoc::parsers::TemplateField`, which a derived
+//! type does too, so derived types nest.
+//!
+//! The derive implements `TemplateField`, `FromStr` with an `aoc::parsers::ParseError`,
+//! and an inherent `parse` usable as a nom parser. An enum tries its variants in order
+//! and takes the first whose format matches and is followed by whatever comes after the
+//! enum in the enclosing format, so keywords pick the variant.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Ident, Lit, LitStr, Meta,
+    NestedMeta, Type,
+};
+
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "AocParse does not support generic types",
+        ));
+    }
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let format = format_attr(&input.attrs, name)?;
+            format_parser(&format, &data.fields, quote!(Self))?
+        }
+        Data::Enum(data) => {
+            let mut variants = vec![];
+            for variant in &data.variants {
+                let format = format_attr(&variant.attrs, &variant.ident)?;
+                let ident = &variant.ident;
+                let parser = format_parser(&format, &variant.fields, quote!(Self::#ident))?;
+                variants.push(quote! {
+                    let variant = |i: &'a str| -> ::aoc::parsers::IResult<&'a str, Self> {
+                        #parser
+                    };
+                    if let Ok((rest, value)) = variant(i) {
+                        if ::aoc::parsers::followed_by(rest, until) {
+                            return Ok((rest, value));
+                        }
+                    }
+                });
+            }
+            quote! {
+                #(#variants)*
+                Err(::aoc::parsers::no_alternative(i))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "AocParse only supports structs and enums",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl<'a> ::aoc::parsers::TemplateField<'a> for #name {
+            #[allow(unused_variables)]
+            fn parse_field(
+                i: &'a str,
+                until: &str,
+            ) -> ::aoc::parsers::IResult<&'a str, Self> {
+                #body
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = ::aoc::parsers::ParseError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                ::aoc::parsers::finish(s, #name::parse(s))
+            }
+        }
+
+        impl #name {
+            /// Parses one value in the derived format, as a nom parser.
+            #[allow(dead_code)]
+            pub fn parse(i: &str) -> ::aoc::parsers::IResult<&str, Self> {
+                <Self as ::aoc::parsers::TemplateField>::parse_field(i, "")
+            }
+        }
+    })
+}
+
+/// The `format` in a `#[aoc(format = "...")]` attribute, which `item` must have.
+fn format_attr(attrs: &[Attribute], item: &Ident) -> syn::Result<LitStr> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path.is_ident("aoc")) else {
+        return Err(Error::new_spanned(
+            item,
+            format!("`{}` needs a `#[aoc(format = \"...\")]` attribute", item),
+        ));
+    };
+    let meta = attr.parse_meta()?;
+    let nested = match &meta {
+        Meta::List(list) if list.nested.len() == 1 => &list.nested[0],
+        _ => {
+            return Err(Error::new_spanned(
+                attr,
+                "expected `#[aoc(format = \"...\")]`",
+            ))
+        }
+    };
+    match nested {
+        NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("format") => {
+            match &pair.lit {
+                Lit::Str(format) => Ok(format.clone()),
+                lit => Err(Error::new_spanned(lit, "format must be a string")),
+            }
+        }
+        _ => Err(Error::new_spanned(nested, "expected `format = \"...\"`")),
+    }
+}
+
+enum Segment {
+    Literal(String),
+    /// `{}`
+    Next,
+    /// `{name}`
+    Named(String),
+    /// `{_}`
+    Skip,
+}
+
+fn parse_format(format: &LitStr) -> syn::Result<Vec<Segment>> {
+    let text = format.value();
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(Error::new_spanned(format, "unclosed `{` in format")),
+                    }
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(match name.trim() {
+                    "" => Segment::Next,
+                    "_" => Segment::Skip,
+                    name => Segment::Named(name.to_string()),
+                });
+            }
+            '}' => return Err(Error::new_spanned(format, "unmatched `}` in format")),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Ok(segments)
+}
+
+/// The body of a parser for `fields` in the given format, ending with building the value
+/// through `constructor`.
+fn format_parser(
+    format: &LitStr,
+    fields: &Fields,
+    constructor: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let segments = parse_format(format)?;
+    let field_types: Vec<(Option<&Ident>, &Type)> =
+        fields.iter().map(|f| (f.ident.as_ref(), &f.ty)).collect();
+    let bindings: Vec<Ident> = field_types
+        .iter()
+        .enumerate()
+        .map(|(n, (ident, _))| match ident {
+            Some(ident) => format_ident!("field_{}", ident),
+            None => format_ident!("field_{}", n),
+        })
+        .collect();
+
+    let mut used = vec![false; field_types.len()];
+    let mut next_unnamed = 0;
+    let mut steps = vec![];
+    for (k, segment) in segments.iter().enumerate() {
+        // Each field reads up to the literal after it, or whatever follows this value.
+        let until = match segments.get(k + 1) {
+            Some(Segment::Literal(literal)) => quote!(#literal),
+            Some(_) => quote!(""),
+            None => quote!(until),
+        };
+        let field = match segment {
+            Segment::Literal(literal) => {
+                steps.push(quote! {
+                    let (i, _) = ::aoc::parsers::literal(i, #literal)?;
+                });
+                continue;
+            }
+            Segment::Skip => {
+                steps.push(quote! {
+                    let (i, _) = <&str as ::aoc::parsers::TemplateField>::parse_field(i, #until)?;
+                });
+                continue;
+            }
+            Segment::Next => {
+                if matches!(fields, Fields::Named(_)) {
+                    return Err(Error::new_spanned(
+                        format,
+                        "use `{name}` placeholders for named fields",
+                    ));
+                }
+                next_unnamed += 1;
+                next_unnamed - 1
+            }
+            Segment::Named(name) => field_types
+                .iter()
+                .position(|(ident, _)| ident.is_some_and(|ident| ident == name))
+                .ok_or_else(|| Error::new_spanned(format, format!("no field named `{}`", name)))?,
+        };
+        let Some((_, ty)) = field_types.get(field) else {
+            return Err(Error::new_spanned(format, "more `{}` than fields"));
+        };
+        if std::mem::replace(&mut used[field], true) {
+            return Err(Error::new_spanned(
+                format,
+                "a field appears twice in the format",
+            ));
+        }
+        let binding = &bindings[field];
+        steps.push(quote! {
+            let (i, #binding) = <#ty as ::aoc::parsers::TemplateField>::parse_field(i, #until)?;
+        });
+    }
+    if used.contains(&false) {
+        return Err(Error::new_spanned(
+            format,
+            "every field must appear in the format",
+        ));
+    }
+
+    let value = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|f| &f.ident);
+            quote!(#constructor { #(#idents: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#constructor(#(#bindings),*)),
+        Fields::Unit => constructor,
+    };
+    Ok(quote! {
+        #(#steps)*
+        Ok((i, #value))
+    })
+}
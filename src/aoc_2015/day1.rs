@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn char_to_step(c: u8) -> Option<i64> {
     match c {
@@ -8,13 +8,14 @@ fn char_to_step(c: u8) -> Option<i64> {
     }
 }
 
-pub fn part1() {
-    let total: i64 = std_iter!(Bytes).filter_map(char_to_step).sum();
-    println!("{}", total);
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let total: i64 = input.bytes().filter_map(char_to_step).sum();
+    Ok(total.into())
 }
 
-pub fn part2() {
-    let position: usize = std_iter!(Bytes)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let position: usize = input
+        .bytes()
         .filter_map(char_to_step)
         .scan(0, |current, step| {
             *current += step;
@@ -24,5 +25,5 @@ pub fn part2() {
         .unwrap()
         .0
         + 1;
-    println!("{}", position);
+    Ok(position.into())
 }
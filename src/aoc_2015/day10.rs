@@ -1,7 +1,10 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let init = std_iter!(Bytes).map(|b| (b - b'0') as u64).collect_vec();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let init = input
+        .trimmed_bytes()
+        .map(|b| (b - b'0') as u64)
+        .collect_vec();
     let n = (0..40).fold(init, |v, _| {
         let mut numbers = vec![v[0]];
         let mut counts = vec![1u64];
@@ -14,16 +17,16 @@ pub fn part1() {
                 counts.push(1);
             }
         }
-        counts
-            .into_iter()
-            .interleave(numbers.into_iter())
-            .collect_vec()
+        counts.into_iter().interleave(numbers).collect_vec()
     });
-    println!("{}", n.len());
+    Ok(n.len().into())
 }
 
-pub fn part2() {
-    let init = std_iter!(Bytes).map(|b| (b - b'0') as u64).collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let init = input
+        .trimmed_bytes()
+        .map(|b| (b - b'0') as u64)
+        .collect_vec();
     let n = (0..50).fold(init, |v, _| {
         let mut numbers = vec![v[0]];
         let mut counts = vec![1u64];
@@ -36,10 +39,7 @@ pub fn part2() {
                 counts.push(1);
             }
         }
-        counts
-            .into_iter()
-            .interleave(numbers.into_iter())
-            .collect_vec()
+        counts.into_iter().interleave(numbers).collect_vec()
     });
-    println!("{}", n.len());
+    Ok(n.len().into())
 }
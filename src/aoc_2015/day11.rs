@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn radix_26_parse(s: &[u8]) -> u64 {
     match s {
@@ -42,18 +42,18 @@ fn password_is_ok(value: u64) -> bool {
     has_consecutive && !contains_bad_char && has_2_pairs
 }
 
-pub fn part1() {
-    let original = std_iter!(Bytes).collect_vec();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let original = input.trimmed_bytes().collect_vec();
     let mut password = radix_26_parse(&original[..]);
     while !password_is_ok(password) {
         password += 1;
     }
     let string = radix_26_encode(password);
-    println!("{}", String::from_utf8(string).unwrap());
+    Ok((String::from_utf8(string).unwrap()).into())
 }
 
-pub fn part2() {
-    let original = std_iter!(Bytes).collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let original = input.trimmed_bytes().collect_vec();
     let mut password = radix_26_parse(&original[..]);
     while !password_is_ok(password) {
         password += 1;
@@ -63,5 +63,5 @@ pub fn part2() {
         password += 1;
     }
     let string = radix_26_encode(password);
-    println!("{}", String::from_utf8(string).unwrap());
+    Ok((String::from_utf8(string).unwrap()).into())
 }
@@ -1,81 +1,57 @@
-use crate::{prelude::*, std_iter};
-
-fn parse_line(input: &str) -> IResult<&str, (&str, &str, i64)> {
-    // Alice would gain 54 happiness units by sitting next to Bob.
-    let (input, name1) = alpha1(input)?;
-    let (input, _) = tag(" would ")(input)?;
-    let (input, direction) = alt((tag("lose "), tag("gain ")))(input)?;
-    let (input, n) = digit1(input)?;
-    let (input, _) = tag(" happiness units by sitting next to ")(input)?;
-    let (input, name2) = alpha1(input)?;
-
-    let happiness = match direction {
-        "lose " => -1i64 * n.parse::<i64>().unwrap(),
-        "gain " => n.parse().unwrap(),
-        _ => unreachable!(),
-    };
-
-    Ok((input, (name1, name2, happiness)))
+use crate::prelude::*;
+
+#[derive(AocParse)]
+enum Change {
+    #[aoc(format = "gain {}")]
+    Gain(i64),
+    #[aoc(format = "lose {}")]
+    Lose(i64),
 }
 
-pub fn part1() {
-    let lines = std_iter!(Lines).collect_vec();
-    let rules = lines
-        .iter()
-        .map(|l| parse_line(l).expect("Parser Error").1)
-        .collect_vec();
-    let nodes: NodeRegistration = rules
-        .iter()
-        .map(|&(n1, n2, _)| [n1, n2])
-        .flatten()
-        .collect();
-    let mut graph = vec![vec![0; nodes.len()]; nodes.len()];
+/// Alice would gain 54 happiness units by sitting next to Bob.
+#[derive(AocParse)]
+#[aoc(format = "{name} would {change} happiness units by sitting next to {neighbor}.")]
+struct Preference {
+    name: String,
+    change: Change,
+    neighbor: String,
+}
 
-    for (n1, n2, value) in rules {
-        graph[nodes.get_id(n1).unwrap()][nodes.get_id(n2).unwrap()] = value;
+fn parse_input(input: &str) -> Result<Graph<i64>, ParseError> {
+    let mut graph = Graph::new();
+    for preference in parse_lines(input, Preference::parse)? {
+        let happiness = match preference.change {
+            Change::Gain(n) => n,
+            Change::Lose(n) => -n,
+        };
+        graph.add_edge(&preference.name, &preference.neighbor, happiness);
     }
-
-    let total: i64 = (0..nodes.len())
-        .permutations(nodes.len())
-        .map(|sittings| {
-            (0..sittings.len())
-                .map(|i| (sittings[i], sittings[(i + 1) % sittings.len()]))
-                .map(|(prev, next)| graph[prev][next] + graph[next][prev])
-                .sum()
-        })
-        .max()
-        .unwrap();
-
-    println!("{}", total);
+    Ok(graph)
 }
 
-pub fn part2() {
-    let lines = std_iter!(Lines).collect_vec();
-    let rules = lines
-        .iter()
-        .map(|l| parse_line(l).expect("Parser Error").1)
+/// The best total change in happiness around a round table.
+fn best_seating(graph: &Graph<i64>) -> i64 {
+    let happiness = |a, b| graph.weight(a, b).unwrap_or(0);
+    // Neighbors affect each other, so seating them together counts both ways.
+    let matrix = (0..graph.len())
+        .map(|a| {
+            (0..graph.len())
+                .map(|b| Some(happiness(a, b) + happiness(b, a)))
+                .collect()
+        })
         .collect_vec();
-    let nodes: NodeRegistration = rules
-        .iter()
-        .map(|&(n1, n2, _)| [n1, n2])
-        .flatten()
-        .collect();
-    let mut graph = vec![vec![0; nodes.len() + 1]; nodes.len() + 1];
-
-    for (n1, n2, value) in rules {
-        graph[nodes.get_id(n1).unwrap()][nodes.get_id(n2).unwrap()] = value;
-    }
+    tsp::hamiltonian_cycle(&matrix, tsp::Objective::Longest)
+        .unwrap()
+        .cost
+}
 
-    let total: i64 = (0..graph.len())
-        .permutations(graph.len())
-        .map(|sittings| {
-            (0..sittings.len())
-                .map(|i| (sittings[i], sittings[(i + 1) % sittings.len()]))
-                .map(|(prev, next)| graph[prev][next] + graph[next][prev])
-                .sum()
-        })
-        .max()
-        .unwrap();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    Ok(best_seating(&parse_input(input)?).into())
+}
 
-    println!("{}", total);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut graph = parse_input(input)?;
+    // Sitting next to yourself changes nobody's happiness.
+    graph.add_node("me");
+    Ok(best_seating(&graph).into())
 }
@@ -1,11 +1,11 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn parse_deer(s: &str) -> IResult<&str, (u64, u64, u64)> {
+fn parse_deer(s: &str) -> Result<(u64, u64, u64), ParseError> {
     // Rudolph can fly 22 km/s for 8 seconds, but then must rest for 165 seconds.
-    let (s, speed) = preceded(take_till(|c: char| c.is_digit(10)), parse_u64)(s)?;
-    let (s, fly_time) = preceded(take_till(|c: char| c.is_digit(10)), parse_u64)(s)?;
-    let (s, rest_time) = preceded(take_till(|c: char| c.is_digit(10)), parse_u64)(s)?;
-    Ok((s, (speed, fly_time, rest_time)))
+    let [speed, fly_time, rest_time] = ints(s)?[..] else {
+        return Err(ParseError::at(s, s, "a speed and two durations"));
+    };
+    Ok((speed, fly_time, rest_time))
 }
 
 fn distance_traveled(deer: &(u64, u64, u64), time: u64) -> u64 {
@@ -16,21 +16,27 @@ fn distance_traveled(deer: &(u64, u64, u64), time: u64) -> u64 {
     n_cycle * fly_time * speed + extra_time.min(fly_time) * speed
 }
 
-pub fn part1() {
-    let max_distance = std_iter!(Lines)
-        .filter_map(|l| parse_deer(&l).ok().and_then(|(_, r)| Some(r)))
-        .map(|deer| distance_traveled(&deer, 2503))
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let max_distance = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(parse_deer)
+        .collect::<Result<Vec<_>, _>>()?
+        .iter()
+        .map(|deer| distance_traveled(deer, 2503))
         .max()
         .unwrap();
-    println!("{}", max_distance);
+    Ok(max_distance.into())
 }
 
-pub fn part2() {
-    let deers = std_iter!(Lines)
-        .filter_map(|l| parse_deer(&l).ok().and_then(|(_, r)| Some(r)))
-        .collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let deers = input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(parse_deer)
+        .collect::<Result<Vec<_>, _>>()?;
     let winner = (1..=2503)
-        .map(|time| {
+        .flat_map(|time| {
             let distances = deers
                 .iter()
                 .map(|deer| distance_traveled(deer, time))
@@ -42,11 +48,10 @@ pub fn part2() {
                 .collect_vec()
                 .into_iter()
         })
-        .flatten()
         .counts()
         .into_values()
         .max()
         .unwrap();
 
-    println!("{}", winner);
+    Ok(winner.into())
 }
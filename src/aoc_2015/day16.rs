@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn target_sue(key: &str) -> i64 {
     match key {
@@ -42,18 +42,18 @@ fn parse_sue(s: &str) -> IResult<&str, Vec<(String, i64)>> {
     Ok((s, values))
 }
 
-pub fn part1() {
-    let (sue_id, _) = std_iter!(Lines)
-        .map(|l| parse_sue(&l).unwrap().1)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let (sue_id, _) = parse_lines(input, parse_sue)?
+        .into_iter()
         .find_position(|sue| sue.iter().all(|(key, v)| target_sue(key) == *v))
         .unwrap();
-    println!("{}", sue_id + 1);
+    Ok((sue_id + 1).into())
 }
 
-pub fn part2() {
-    let (sue_id, _) = std_iter!(Lines)
-        .map(|l| parse_sue(&l).unwrap().1)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let (sue_id, _) = parse_lines(input, parse_sue)?
+        .into_iter()
         .find_position(|sue| sue.iter().all(|(key, v)| target_sue2(key, *v)))
         .unwrap();
-    println!("{}", sue_id + 1);
+    Ok((sue_id + 1).into())
 }
@@ -1,66 +1,21 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn count_partitions(containers: &[i64], remaining_count: i64) -> usize {
-    if remaining_count < 0 {
-        return 0;
-    }
-    if remaining_count == 0 {
-        return 1;
-    }
-    match containers {
-        [] => 0,
-        [head, tail @ ..] => {
-            count_partitions(tail, remaining_count)
-                + count_partitions(tail, remaining_count - *head)
-        }
-    }
-}
+const EGGNOG: usize = 150;
 
-pub fn part1() {
-    let containers: Vec<i64> = std_iter!(Lines)
-        .map(|l| l.parse().unwrap())
-        .sorted()
-        .collect_vec();
-    let count = count_partitions(&containers[..], 150);
-    println!("{}", count);
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_lines(input, parse_usize)
 }
 
-fn accumulate_partitions(
-    containers: &[i64],
-    remaining_count: i64,
-    container_count: i64,
-) -> Vec<i64> {
-    if remaining_count < 0 {
-        return vec![];
-    }
-    if remaining_count == 0 {
-        return vec![container_count];
-    }
-    match containers {
-        [] => vec![],
-        [head, tail @ ..] => {
-            let mut partitions = accumulate_partitions(tail, remaining_count, container_count);
-            partitions.append(&mut accumulate_partitions(
-                tail,
-                remaining_count - *head,
-                container_count + 1,
-            ));
-            partitions
-        }
-    }
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let containers = parse_input(input)?;
+    Ok(combinatorics::count_subsets_with_sum(&containers, EGGNOG).into())
 }
 
-pub fn part2() {
-    let containers: Vec<i64> = std_iter!(Lines)
-        .map(|l| l.parse().unwrap())
-        .sorted()
-        .collect_vec();
-    let (_, count) = accumulate_partitions(&containers[..], 150, 0)
-        .into_iter()
-        .counts()
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let containers = parse_input(input)?;
+    let count = combinatorics::subset_sum_counts_by_size(&containers, EGGNOG)
         .into_iter()
-        .min()
+        .find(|&count| count > 0)
         .unwrap();
-
-    println!("{}", count);
+    Ok(count.into())
 }
@@ -1,75 +1,37 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let mut grid = std_iter!(Lines)
-        .map(|l| {
-            [false]
-                .into_iter()
-                .chain(l.chars().map(|c| c == '#'))
-                .chain([false])
-                .collect_vec()
-        })
-        .collect_vec();
+fn parse_input(input: &Input) -> Result<Grid<bool>, ParseError> {
+    input.grid_of(|b| b == b'#')
+}
 
-    grid.insert(0, vec![false; grid[0].len()]);
-    grid.push(vec![false; grid[0].len()]);
+fn step(grid: &Grid<bool>) -> Grid<bool> {
+    Grid::from_fn(grid.width(), grid.height(), |coord| {
+        let count = grid.neighbors8(coord).filter(|&n| grid[n]).count();
+        matches!((grid[coord], count), (true, 2) | (_, 3))
+    })
+}
 
-    for _step in 0..100 {
-        let mut counts = vec![vec![0; grid[0].len()]; grid.len()];
-        for (y, x) in iproduct!(1..grid.len() - 1, 1..grid[0].len() - 1) {
-            counts[y][x] = iproduct!([y - 1, y, y + 1], [x - 1, x, x + 1])
-                .filter(|&(ny, nx)| (nx != x || ny != y) && grid[ny][nx])
-                .count();
-        }
-        for (y, x) in iproduct!(1..grid.len() - 1, 1..grid[0].len() - 1) {
-            grid[y][x] = match (grid[y][x], counts[y][x]) {
-                (true, 2) | (_, 3) => true,
-                _ => false,
-            }
-        }
+fn light_corners(grid: &mut Grid<bool>) {
+    let (last_row, last_col) = (grid.height() - 1, grid.width() - 1);
+    for (row, col) in iproduct!([0, last_row], [0, last_col]) {
+        grid[Coord::new(row, col)] = true;
     }
-    let count = iproduct!(1..grid.len() - 1, 1..grid[0].len() - 1)
-        .filter(|&(y, x)| grid[y][x])
-        .count();
-    println!("{}", count);
 }
 
-pub fn part2() {
-    let mut grid = std_iter!(Lines)
-        .map(|l| {
-            [false]
-                .into_iter()
-                .chain(l.chars().map(|c| c == '#'))
-                .chain([false])
-                .collect_vec()
-        })
-        .collect_vec();
-
-    grid.insert(0, vec![false; grid[0].len()]);
-    grid.push(vec![false; grid[0].len()]);
-
-    let max_y = grid.len() - 1;
-    let max_x = grid[0].len() - 1;
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    // Lights tend to settle into still lifes and blinkers, after which the states repeat.
+    let grid = simulate(parse_input(input)?, 100, step).state;
+    Ok(grid.values().filter(|&&on| on).count().into())
+}
 
-    for _step in 0..100 {
-        let mut counts = vec![vec![0; grid[0].len()]; grid.len()];
-        for (y, x) in iproduct!(1..max_y, 1..max_x) {
-            counts[y][x] = iproduct!([y - 1, y, y + 1], [x - 1, x, x + 1])
-                .filter(|&(ny, nx)| (nx != x || ny != y) && grid[ny][nx])
-                .count();
-        }
-        for (y, x) in iproduct!(1..max_y, 1..max_x) {
-            grid[y][x] = match (grid[y][x], counts[y][x]) {
-                (true, 2) | (_, 3) => true,
-                _ => false,
-            }
-        }
-        for (y, x) in iproduct!([1, max_y - 1], [1, max_x - 1]) {
-            grid[y][x] = true;
-        }
-    }
-    let count = iproduct!(1..max_y, 1..max_x)
-        .filter(|&(y, x)| grid[y][x])
-        .count();
-    println!("{}", count);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut grid = parse_input(input)?;
+    light_corners(&mut grid);
+    let grid = simulate(grid, 100, |grid| {
+        let mut next = step(grid);
+        light_corners(&mut next);
+        next
+    })
+    .state;
+    Ok(grid.values().filter(|&&on| on).count().into())
 }
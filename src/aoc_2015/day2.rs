@@ -1,8 +1,8 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let total: i64 = std_iter!(Lines)
-        .map(|line| separated_list0(tag("x"), parse_i64)(&line).unwrap().1)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let total: i64 = parse_lines(input, separated_list0(tag("x"), parse_i64))?
+        .into_iter()
         .map(|numbers| {
             let (total_sa, min_sa) = numbers
                 .into_iter()
@@ -12,12 +12,12 @@ pub fn part1() {
             total_sa + min_sa
         })
         .sum();
-    println!("{}", total);
+    Ok(total.into())
 }
 
-pub fn part2() {
-    let total: i64 = std_iter!(Lines)
-        .map(|line| separated_list0(tag("x"), parse_i64)(&line).unwrap().1)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let total: i64 = parse_lines(input, separated_list0(tag("x"), parse_i64))?
+        .into_iter()
         .map(|numbers| {
             let volume: i64 = numbers.iter().product();
             let shortest_waist: i64 = numbers
@@ -30,5 +30,5 @@ pub fn part2() {
             shortest_waist + volume
         })
         .sum();
-    println!("{}", total);
+    Ok(total.into())
 }
@@ -1,7 +1,34 @@
-pub fn part1() {
-    todo!()
+use crate::prelude::*;
+
+fn parse_input(input: &str) -> Result<usize, ParseError> {
+    parse_all(input, parse_usize)
+}
+
+/// The first house, counting from 1, that gets at least `target` presents.
+fn first_house(presents: &[u64], target: usize) -> usize {
+    presents
+        .iter()
+        .skip(1)
+        .position(|&p| p as usize >= target)
+        .unwrap()
+        + 1
+}
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let target = parse_input(input)?;
+    // House `h` gets at least `10 * h` presents from the elf with the same number.
+    let presents = math::divisor_sums(target / 10 + 2)
+        .into_iter()
+        .map(|sum| sum * 10)
+        .collect_vec();
+    Ok(first_house(&presents, target).into())
 }
 
-pub fn part2() {
-    todo!()
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let target = parse_input(input)?;
+    let presents = math::divisor_sums_capped(target / 11 + 2, 50)
+        .into_iter()
+        .map(|sum| sum * 11)
+        .collect_vec();
+    Ok(first_house(&presents, target).into())
 }
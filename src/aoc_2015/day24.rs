@@ -1,7 +1,51 @@
-pub fn part1() {
-    todo!()
+use crate::prelude::*;
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_lines(input, parse_usize)
+}
+
+/// Whether the packages can be split into `groups` groups of equal weight.
+fn can_split(packages: &[usize], groups: usize) -> bool {
+    let total: usize = packages.iter().sum();
+    if groups == 1 {
+        return true;
+    }
+    if !total.is_multiple_of(groups) {
+        return false;
+    }
+    combinatorics::any_subset_with_sum(packages, total / groups, |group| {
+        can_split(&without(packages, group), groups - 1)
+    })
+}
+
+fn without(packages: &[usize], group: &[usize]) -> Vec<usize> {
+    packages
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !group.contains(i))
+        .map(|(_, &p)| p)
+        .collect()
+}
+
+/// The lowest quantum entanglement of a smallest possible passenger group.
+fn balance(packages: &[usize], groups: usize) -> usize {
+    let target = packages.iter().sum::<usize>() / groups;
+    (1..=packages.len())
+        .find_map(|size| {
+            combinatorics::k_subsets_with_sum(packages, size, target)
+                .into_iter()
+                .map(|group| (group.iter().map(|&i| packages[i]).product(), group))
+                .sorted()
+                .find(|(_, group)| can_split(&without(packages, group), groups - 1))
+                .map(|(entanglement, _)| entanglement)
+        })
+        .unwrap()
+}
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    Ok(balance(&parse_input(input)?, 3).into())
 }
 
-pub fn part2() {
-    todo!()
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    Ok(balance(&parse_input(input)?, 4).into())
 }
@@ -1,7 +1,23 @@
-pub fn part1() {
-    todo!()
+use nom::bytes::complete::take_until;
+
+use crate::prelude::*;
+
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+fn parse_input(i: &str) -> ParserResult<'_, (u64, u64)> {
+    let (i, _) = take_until("row ")(i)?;
+    let (i, row) = preceded(tag("row "), parse_u64)(i)?;
+    let (i, col) = preceded(tag(", column "), parse_u64)(i)?;
+    let (i, _) = tag(".")(i)?;
+    Ok((i, (row, col)))
 }
 
-pub fn part2() {
-    todo!()
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let (row, col) = parse_all(input, parse_input)?;
+    // Codes fill the grid diagonal by diagonal, from the bottom-left of each.
+    let diagonal = row + col - 1;
+    let index = diagonal * (diagonal - 1) / 2 + col - 1;
+    Ok((FIRST_CODE * math::mod_pow(MULTIPLIER, index, MODULUS) % MODULUS).into())
 }
@@ -1,7 +1,8 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let count = std_iter!(Bytes)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let count = input
+        .bytes()
         .scan((0, 0), |(x, y), step| {
             match step {
                 b'>' => *x += 1,
@@ -12,33 +13,34 @@ pub fn part1() {
             };
             Some((*x, *y))
         })
-        .chain(vec![(0, 0)].into_iter())
+        .chain(vec![(0, 0)])
         .unique()
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
 
-pub fn part2() {
-    let (script_santa, script_robot) = std_iter!(Bytes).tee();
-
-    let count = vec![script_santa.skip(0), script_robot.skip(1)]
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let count = [0, 1]
         .into_iter()
-        .map(|instructions| {
-            instructions.step_by(2).scan((0, 0), |(x, y), step| {
-                match step {
-                    b'>' => *x += 1,
-                    b'<' => *x -= 1,
-                    b'^' => *y += 1,
-                    b'v' => *y -= 1,
-                    _ => unreachable!(),
-                };
-                Some((*x, *y))
-            })
+        .flat_map(|offset| {
+            input
+                .bytes()
+                .skip(offset)
+                .step_by(2)
+                .scan((0, 0), |(x, y), step| {
+                    match step {
+                        b'>' => *x += 1,
+                        b'<' => *x -= 1,
+                        b'^' => *y += 1,
+                        b'v' => *y -= 1,
+                        _ => unreachable!(),
+                    };
+                    Some((*x, *y))
+                })
         })
-        .flatten()
-        .chain(vec![(0, 0)].into_iter())
+        .chain(vec![(0, 0)])
         .unique()
         .count();
 
-    println!("{}", count);
+    Ok(count.into())
 }
@@ -1,7 +1,7 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn collision(key: &[u8], target: &[u8]) -> i32 {
-    let l = (target.len() + 1) / 2;
+    let l = target.len().div_ceil(2);
     (1..i32::MAX)
         .find(|i| {
             let crack = [key, i.to_string().as_bytes()].concat();
@@ -17,14 +17,14 @@ fn collision(key: &[u8], target: &[u8]) -> i32 {
         .unwrap()
 }
 
-pub fn part1() {
-    let key: Vec<u8> = std_iter!(Bytes).filter(|c| c.is_alphanum()).collect();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let key: Vec<u8> = input.bytes().filter(|c| c.is_alphanum()).collect();
     let i = collision(&key, &[0, 0, 0, 0, 0]);
-    println!("{}", i);
+    Ok(i.into())
 }
 
-pub fn part2() {
-    let key: Vec<u8> = std_iter!(Bytes).filter(|c| c.is_alphanum()).collect();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let key: Vec<u8> = input.bytes().filter(|c| c.is_alphanum()).collect();
     let i = collision(&key, &[0, 0, 0, 0, 0, 0]);
-    println!("{}", i);
+    Ok(i.into())
 }
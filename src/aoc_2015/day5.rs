@@ -1,40 +1,37 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let count = std_iter!(Lines)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let count = input
+        .lines()
         .filter(|line| {
             let vowel_count = line
                 .bytes()
-                .filter(|&b| match b {
-                    b'a' | b'e' | b'i' | b'o' | b'u' => true,
-                    _ => false,
-                })
+                .filter(|&b| matches!(b, b'a' | b'e' | b'i' | b'o' | b'u'))
                 .count();
 
             let twice_in_a_row = line.bytes().tuple_windows().any(|(x, y)| x == y);
 
-            let contains_bad_sub = line.bytes().tuple_windows().any(|(x, y)| match &[x, y] {
-                b"ab" | b"cd" | b"pq" | b"xy" => true,
-                _ => false,
-            });
+            let contains_bad_sub = line
+                .bytes()
+                .tuple_windows()
+                .any(|(x, y)| matches!(&[x, y], b"ab" | b"cd" | b"pq" | b"xy"));
 
             (vowel_count >= 3) && twice_in_a_row && (!contains_bad_sub)
         })
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
 
-pub fn part2() {
-    let count = std_iter!(Lines)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let count = input
+        .lines()
         .filter(|line| {
             let two_grams = line
                 .bytes()
                 .tuple_windows::<(u8, u8)>()
                 .enumerate()
                 .scan(HashMap::new(), |first_positions, (i, pair)| {
-                    if !first_positions.contains_key(&pair) {
-                        first_positions.insert(pair, i);
-                    }
+                    first_positions.entry(pair).or_insert(i);
                     let first_pos = first_positions.get(&pair).unwrap();
                     Some(i - first_pos > 1)
                 })
@@ -44,5 +41,5 @@ pub fn part2() {
             sandwiched && two_grams
         })
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 type Rect = ((usize, usize), (usize, usize));
 
@@ -31,9 +31,9 @@ macro_rules! rect_coords {
     };
 }
 
-pub fn part1() {
-    let count: u32 = std_iter!(Lines)
-        .map(|l| parse_instruction(&l).unwrap().1)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let count: u32 = parse_lines(input, parse_instruction)?
+        .into_iter()
         .fold(vec![0u32; 1000 * 1000], |mut grid, instruction| {
             match instruction {
                 Instruction::Toggle(rect) => {
@@ -50,12 +50,12 @@ pub fn part1() {
         })
         .into_iter()
         .sum();
-    println!("{}", count);
+    Ok(count.into())
 }
 
-pub fn part2() {
-    let count: i64 = std_iter!(Lines)
-        .map(|l| parse_instruction(&l).unwrap().1)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let count: i64 = parse_lines(input, parse_instruction)?
+        .into_iter()
         .fold(vec![0i64; 1000 * 1000], |mut grid, instruction| {
             match instruction {
                 Instruction::Toggle(rect) => {
@@ -71,5 +71,5 @@ pub fn part2() {
         })
         .into_iter()
         .sum();
-    println!("{}", count);
+    Ok(count.into())
 }
@@ -1,49 +1,42 @@
-use std::str::FromStr;
+use crate::prelude::*;
 
-use crate::{prelude::*, std_iter};
-
-#[derive(Debug)]
+#[derive(Debug, AocParse)]
 enum CircuitNode {
+    #[aoc(format = "{}")]
     Value(u16),
+    #[aoc(format = "{}")]
     Node(String),
 }
 
-impl FromStr for CircuitNode {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s.parse() {
-            Ok(v) => Self::Value(v),
-            Err(_) => Self::Node(s.to_string()),
-        })
-    }
-}
-
+/// Variants are tried in order, so the bare wire comes last.
+#[derive(AocParse)]
 enum CircuitConnection {
-    Assign(CircuitNode),
+    #[aoc(format = "NOT {}")]
     Not(CircuitNode),
+    #[aoc(format = "{} AND {}")]
     And(CircuitNode, CircuitNode),
+    #[aoc(format = "{} OR {}")]
     Or(CircuitNode, CircuitNode),
+    #[aoc(format = "{} RSHIFT {}")]
     RightShift(CircuitNode, CircuitNode),
+    #[aoc(format = "{} LSHIFT {}")]
     LeftShift(CircuitNode, CircuitNode),
+    #[aoc(format = "{}")]
+    Assign(CircuitNode),
 }
 
-fn parse_circuit_instruction(l: &str) -> (String, CircuitConnection) {
-    let (lhs, rhs) = l.split(" -> ").collect_tuple().unwrap();
-    let rhs = rhs.to_string();
-    let tokens = lhs.split(" ").collect_vec();
-
-    let connection = match tokens[..] {
-        [x] => CircuitConnection::Assign(x.parse().unwrap()),
-        ["NOT", x] => CircuitConnection::Not(x.parse().unwrap()),
-        [x, "AND", y] => CircuitConnection::And(x.parse().unwrap(), y.parse().unwrap()),
-        [x, "OR", y] => CircuitConnection::Or(x.parse().unwrap(), y.parse().unwrap()),
-        [x, "RSHIFT", y] => CircuitConnection::RightShift(x.parse().unwrap(), y.parse().unwrap()),
-        [x, "LSHIFT", y] => CircuitConnection::LeftShift(x.parse().unwrap(), y.parse().unwrap()),
-        _ => unreachable!(),
-    };
+#[derive(AocParse)]
+#[aoc(format = "{connection} -> {wire}")]
+struct CircuitInstruction {
+    connection: CircuitConnection,
+    wire: String,
+}
 
-    return (rhs, connection);
+fn parse_circuit(input: &str) -> Result<HashMap<String, CircuitConnection>, ParseError> {
+    Ok(parse_lines(input, CircuitInstruction::parse)?
+        .into_iter()
+        .map(|instruction| (instruction.wire, instruction.connection))
+        .collect())
 }
 
 fn visit(
@@ -78,20 +71,16 @@ fn visit(
     }
 }
 
-pub fn part1() {
-    let circuit: HashMap<String, CircuitConnection> = std_iter!(Lines)
-        .map(|l| parse_circuit_instruction(&l))
-        .collect();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let circuit = parse_circuit(input)?;
 
     let mut memory: HashMap<String, u16> = HashMap::new();
     let wire_a = visit(&CircuitNode::Node(String::from("a")), &circuit, &mut memory);
-    println!("{}", wire_a);
+    Ok(wire_a.into())
 }
 
-pub fn part2() {
-    let circuit: HashMap<String, CircuitConnection> = std_iter!(Lines)
-        .map(|l| parse_circuit_instruction(&l))
-        .collect();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let circuit = parse_circuit(input)?;
 
     let mut memory = HashMap::new();
     let wire_a = visit(&CircuitNode::Node(String::from("a")), &circuit, &mut memory);
@@ -99,5 +88,5 @@ pub fn part2() {
     let mut memory = HashMap::from_iter([("b".to_string(), wire_a)]);
     let wire_a = visit(&CircuitNode::Node(String::from("a")), &circuit, &mut memory);
 
-    println!("{}", wire_a);
+    Ok(wire_a.into())
 }
@@ -1,4 +1,4 @@
-use crate::std_iter;
+use crate::prelude::*;
 
 fn decode_string(s: &[u8]) -> Option<Vec<u8>> {
     match s {
@@ -40,16 +40,18 @@ fn encode_string(s: &[u8]) -> Vec<u8> {
     builder
 }
 
-pub fn part1() {
-    let result: usize = std_iter!(Lines)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let result: usize = input
+        .lines()
         .map(|l| l.len() - decode_string(l.as_bytes()).unwrap().len())
         .sum();
-    println!("{}", result);
+    Ok(result.into())
 }
 
-pub fn part2() {
-    let result: usize = std_iter!(Lines)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let result: usize = input
+        .lines()
         .map(|l| encode_string(l.as_bytes()).len() - l.len())
         .sum();
-    println!("{}", result);
+    Ok(result.into())
 }
@@ -1,77 +1,27 @@
-use crate::{prelude::*, std_iter};
+use crate::{debug_log, prelude::*};
 
-pub fn part1() {
-    let lines = std_iter!(Lines).collect_vec();
-    let tokens = lines
-        .iter()
-        .map(|l| l.split(" ").collect_vec())
-        .collect_vec();
-
-    let nodes: HashMap<&str, usize> = tokens
-        .iter()
-        .map(|l| [l[0], l[2]])
-        .flatten()
-        .unique()
-        .enumerate()
-        .map(|(i, s)| (s, i))
-        .collect();
-
-    let mut graph = vec![vec![0; nodes.len()]; nodes.len()];
-
-    for line in tokens.iter() {
-        let c1 = nodes.get(line[0]).unwrap();
-        let c2 = nodes.get(line[2]).unwrap();
-        graph[*c1][*c2] = line[4].parse().unwrap();
-        graph[*c2][*c1] = line[4].parse().unwrap();
+fn parse_input(input: &str) -> Result<Graph<u64>, ParseError> {
+    let mut graph = Graph::new();
+    for (from, to, distance) in parse_lines(input, template::<(&str, &str, u64)>("{} to {} = {}"))?
+    {
+        graph.add_undirected_edge(from, to, distance);
     }
-
-    let min_distance = (0..nodes.len())
-        .permutations(nodes.len())
-        .map(|x| {
-            x.iter()
-                .zip(x.iter().skip(1))
-                .map(|(&a, &b)| graph[a][b])
-                .sum::<u64>()
-        })
-        .min()
-        .unwrap();
-    println!("{}", min_distance);
+    Ok(graph)
 }
 
-pub fn part2() {
-    let lines = std_iter!(Lines).collect_vec();
-    let tokens = lines
-        .iter()
-        .map(|l| l.split(" ").collect_vec())
-        .collect_vec();
-
-    let nodes: HashMap<&str, usize> = tokens
-        .iter()
-        .map(|l| [l[0], l[2]])
-        .flatten()
-        .unique()
-        .enumerate()
-        .map(|(i, s)| (s, i))
-        .collect();
-
-    let mut graph = vec![vec![0; nodes.len()]; nodes.len()];
+fn best_route(graph: &Graph<u64>, objective: tsp::Objective) -> u64 {
+    let tour = tsp::hamiltonian_path(&graph.adjacency_matrix(), objective).unwrap();
+    debug_log!(
+        "{}",
+        tour.order.iter().map(|&id| graph.name(id)).join(" -> ")
+    );
+    tour.cost
+}
 
-    for line in tokens.iter() {
-        let c1 = nodes.get(line[0]).unwrap();
-        let c2 = nodes.get(line[2]).unwrap();
-        graph[*c1][*c2] = line[4].parse().unwrap();
-        graph[*c2][*c1] = line[4].parse().unwrap();
-    }
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    Ok(best_route(&parse_input(input)?, tsp::Objective::Shortest).into())
+}
 
-    let max_distance = (0..nodes.len())
-        .permutations(nodes.len())
-        .map(|x| {
-            x.iter()
-                .zip(x.iter().skip(1))
-                .map(|(&a, &b)| graph[a][b])
-                .sum::<u64>()
-        })
-        .max()
-        .unwrap();
-    println!("{}", max_distance);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    Ok(best_route(&parse_input(input)?, tsp::Objective::Longest).into())
 }
@@ -1,21 +1,21 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let count = std_iter!(Lines)
-        .map(|s| s.parse::<u32>().unwrap())
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let count = parse_lines(input, parse_int::<u32>)?
+        .into_iter()
         .tuple_windows()
         .filter(|(prev, next)| next > prev)
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
 
-pub fn part2() {
-    let count = std_iter!(Lines)
-        .map(|s| s.parse::<u32>().unwrap())
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let count = parse_lines(input, parse_int::<u32>)?
+        .into_iter()
         .tuple_windows()
         .map(|(n1, n2, n3)| n1 + n2 + n3)
         .tuple_windows()
         .filter(|(prev, next)| next > prev)
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 enum LineProcessResult {
     IllegalChar(u8),
@@ -32,9 +32,10 @@ fn process_line(s: &str) -> LineProcessResult {
     LineProcessResult::UnmatchedChars(stack)
 }
 
-pub fn part1() {
-    let score = std_iter!(Lines)
-        .filter_map(|l| match process_line(&l) {
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let score = input
+        .lines()
+        .filter_map(|l| match process_line(l) {
             LineProcessResult::IllegalChar(c) => Some(c),
             _ => None,
         })
@@ -46,12 +47,13 @@ pub fn part1() {
             _ => unreachable!(),
         })
         .sum::<u64>();
-    println!("{}", score);
+    Ok(score.into())
 }
 
-pub fn part2() {
-    let mut scores = std_iter!(Lines)
-        .filter_map(|l| match process_line(&l) {
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut scores = input
+        .lines()
+        .filter_map(|l| match process_line(l) {
             LineProcessResult::UnmatchedChars(chars) => Some(chars),
             _ => None,
         })
@@ -71,5 +73,5 @@ pub fn part2() {
         .collect_vec();
 
     scores.sort_unstable();
-    println!("{}", scores[scores.len() / 2]);
+    Ok((scores[scores.len() / 2]).into())
 }
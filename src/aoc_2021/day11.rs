@@ -1,57 +1,41 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn make_neighbors2(
-    y: usize,
-    x: usize,
-    width: usize,
-    height: usize,
-) -> impl Iterator<Item = (usize, usize)> {
-    let x_min = if x > 0 { x - 1 } else { 0 };
-    let x_max = if x < width - 1 { x + 2 } else { width };
-    let y_min = if y > 0 { y - 1 } else { 0 };
-    let y_max = if y < height - 1 { y + 2 } else { height };
-    (y_min..y_max)
-        .cartesian_product(x_min..x_max)
-        .filter(move |&coord| coord != (y, x))
-}
-
-fn octopus_step(grid: &mut Vec<Vec<u8>>) -> usize {
-    let width = grid[0].len();
-    let height = grid.len();
-    let mut stack: VecDeque<(usize, usize)> = iproduct!(0..height, 0..width).collect();
-    while let Some((y, x)) = stack.pop_front() {
-        match grid[y][x] {
+fn octopus_step(grid: &mut Grid<u8>) -> usize {
+    let mut stack: VecDeque<Coord> = grid.coords().collect();
+    while let Some(coord) = stack.pop_front() {
+        match grid[coord] {
             10 /* Already flashed */ => (),
             9 => {
-                grid[y][x] += 1;
-                stack.extend(make_neighbors2(y, x, width, height));
+                grid[coord] += 1;
+                stack.extend(grid.neighbors8(coord));
             },
             n => {
-                grid[y][x] = n + 1;
+                grid[coord] = n + 1;
             }
         }
     }
-    grid.iter_mut()
-        .map(|row| row.iter_mut())
-        .flatten()
-        .filter_map(|x| if *x == 10 { Some(*x = 0) } else { None })
+    grid.values_mut()
+        .filter_map(|x| {
+            if *x == 10 {
+                let _: () = *x = 0;
+                Some(())
+            } else {
+                None
+            }
+        })
         .count()
 }
 
-pub fn part1() {
-    let mut grid = std_iter!(Lines)
-        .map(|l| l.bytes().map(|b| b - b'0').collect_vec())
-        .collect_vec();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let mut grid = input.grid_of(|b| b - b'0')?;
     let result = (0..100).map(|_| octopus_step(&mut grid)).sum::<usize>();
-    println!("{}", result);
+    Ok(result.into())
 }
 
-pub fn part2() {
-    let mut grid = std_iter!(Lines)
-        .map(|l| l.bytes().map(|b| b - b'0').collect_vec())
-        .collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut grid = input.grid_of(|b| b - b'0')?;
     let step = (1..usize::MAX)
-        .find(|_| octopus_step(&mut grid) == grid.len() * grid[0].len())
+        .find(|_| octopus_step(&mut grid) == grid.width() * grid.height())
         .unwrap();
-    println!("{}", step);
+    Ok(step.into())
 }
@@ -1,75 +1,59 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn visit_submarine_graph<'a>(
-    node: &'a str,
-    edges: &HashMap<&str, Vec<&str>>,
-    mut visited: Vec<&'a str>,
-) -> usize {
-    if node == "end" {
-        return 1;
+struct Caves {
+    graph: Graph<()>,
+    small: Vec<bool>,
+    start: usize,
+    end: usize,
+}
+
+fn parse_input(input: &str) -> Result<Caves, ParseError> {
+    let mut graph = Graph::new();
+    for (lhs, rhs) in parse_lines(input, template::<(&str, &str)>("{}-{}"))? {
+        graph.add_undirected_edge(lhs, rhs, ());
     }
-    visited.push(node);
-    edges[node]
-        .iter()
-        .filter(|&neighbor| {
-            !(neighbor.chars().next().unwrap().is_lowercase() && visited.contains(neighbor))
-        })
-        .map(|&destination| visit_submarine_graph(destination, edges, visited.clone()))
-        .sum()
+    let small = (0..graph.len())
+        .map(|id| graph.name(id).chars().next().unwrap().is_lowercase())
+        .collect();
+    Ok(Caves {
+        start: graph.id("start").unwrap(),
+        end: graph.id("end").unwrap(),
+        graph,
+        small,
+    })
 }
 
-fn visit_submarine_graph2<'a>(
-    node: &'a str,
-    edges: &HashMap<&str, Vec<&str>>,
-    mut visited: Vec<&'a str>,
-) -> usize {
-    if node == "end" {
+/// Counts the paths from `node` to the end. Small caves can be visited once, except that
+/// a single one may be visited twice while `revisit` is still available.
+fn count_paths(caves: &Caves, node: usize, visited: &mut Vec<usize>, revisit: bool) -> usize {
+    if node == caves.end {
         return 1;
     }
     visited.push(node);
-    edges[node]
+    let count = caves
+        .graph
+        .neighbors(node)
         .iter()
-        .filter(|&neighbor| *neighbor != "start")
-        .map(|&destination| {
-            if destination.chars().next().unwrap().is_uppercase() {
-                visit_submarine_graph2(destination, edges, visited.clone())
+        .map(|&(next, ())| {
+            if !caves.small[next] || !visited.contains(&next) {
+                count_paths(caves, next, visited, revisit)
+            } else if revisit && next != caves.start {
+                count_paths(caves, next, visited, false)
             } else {
-                match visited.iter().filter(|&n| *n == destination).count() {
-                    0 => visit_submarine_graph2(destination, edges, visited.clone()),
-                    1 => visit_submarine_graph(destination, edges, visited.clone()),
-                    _ => 0,
-                }
+                0
             }
         })
-        .sum()
+        .sum();
+    visited.pop();
+    count
 }
 
-pub fn part1() {
-    let lines = std_iter!(Lines).collect_vec();
-    let edges: HashMap<&str, Vec<&str>> = lines
-        .iter()
-        .map(|line| line.split("-").collect_tuple::<(&str, &str)>().unwrap())
-        .map(|(lhs, rhs)| [(lhs, rhs), (rhs, lhs)])
-        .flatten()
-        .sorted_unstable()
-        .group_by(|&(a, _)| a)
-        .into_iter()
-        .map(|(key, group)| (key, group.into_iter().map(|p| p.1).collect_vec()))
-        .collect();
-    println!("{}", visit_submarine_graph("start", &edges, vec![]));
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let caves = parse_input(input)?;
+    Ok(count_paths(&caves, caves.start, &mut vec![], false).into())
 }
 
-pub fn part2() {
-    let lines = std_iter!(Lines).collect_vec();
-    let edges: HashMap<&str, Vec<&str>> = lines
-        .iter()
-        .map(|line| line.split("-").collect_tuple::<(&str, &str)>().unwrap())
-        .map(|(lhs, rhs)| [(lhs, rhs), (rhs, lhs)])
-        .flatten()
-        .sorted_unstable()
-        .group_by(|&(a, _)| a)
-        .into_iter()
-        .map(|(key, group)| (key, group.into_iter().map(|p| p.1).collect_vec()))
-        .collect();
-    println!("{}", visit_submarine_graph2("start", &edges, vec![]));
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let caves = parse_input(input)?;
+    Ok(count_paths(&caves, caves.start, &mut vec![], true).into())
 }
@@ -1,14 +1,20 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
+
+type Points = Vec<(u64, u64)>;
+type Folds = Vec<(char, u64)>;
 
 fn parse_fold(l: &str) -> IResult<&str, (char, u64)> {
     preceded(
         tag("fold along "),
-        separated_pair(alt((char('x'), char('y'))), char('='), digit0),
+        separated_pair(alt((char('x'), char('y'))), char('='), parse_u64),
     )(l)
-    .and_then(|(l, (x_y, n))| Ok((l, (x_y, n.parse().unwrap()))))
 }
 
-fn print_board(points: &Vec<(u64, u64)>) {
+fn parse_input(input: &str) -> Result<(Points, Folds), ParseError> {
+    parse_sections(input, (lines_of(template("{},{}")), lines_of(parse_fold)))
+}
+
+fn render_board(points: &[(u64, u64)]) -> Vec<String> {
     let (width, height) = points.iter().fold((0, 0), |(max_x, max_y), &(x, y)| {
         (max_x.max(x), max_y.max(y))
     });
@@ -16,65 +22,44 @@ fn print_board(points: &Vec<(u64, u64)>) {
     for &(x, y) in points.iter() {
         board[y as usize][x as usize] = '█';
     }
-    for line in board.into_iter() {
-        for c in line.into_iter() {
-            print!("{}", c);
-        }
-        println!();
-    }
+    board
+        .into_iter()
+        .map(|line| line.into_iter().collect())
+        .collect()
 }
 
-pub fn part1() {
-    let lines = std_iter!(Lines).collect_vec();
-    let points: Vec<(u64, u64)> = lines
-        .iter()
-        .filter_map(|l| l.split(",").collect_tuple::<(&str, &str)>())
-        .map(|(x, y)| (x.parse().unwrap(), y.parse().unwrap()))
-        .collect_vec();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let (points, folds) = parse_input(input)?;
 
-    let final_points = lines
-        .iter()
-        .skip(points.len() + 1)
-        .take(1)
-        .map(|l| parse_fold(l.as_str()).expect("failed to parse fold").1)
-        .fold(points, |points, (x_y, n)| {
-            points
-                .into_iter()
-                .map(|(x, y)| {
-                    let x = if x_y == 'x' && x > n { 2 * n - x } else { x };
-                    let y = if x_y == 'y' && y > n { 2 * n - y } else { y };
-                    (x, y)
-                })
-                .unique()
-                .collect_vec()
-        });
+    let final_points = folds.into_iter().take(1).fold(points, |points, (x_y, n)| {
+        points
+            .into_iter()
+            .map(|(x, y)| {
+                let x = if x_y == 'x' && x > n { 2 * n - x } else { x };
+                let y = if x_y == 'y' && y > n { 2 * n - y } else { y };
+                (x, y)
+            })
+            .unique()
+            .collect_vec()
+    });
 
-    println!("{}", final_points.len());
+    Ok(final_points.len().into())
 }
 
-pub fn part2() {
-    let lines = std_iter!(Lines).collect_vec();
-    let points: Vec<(u64, u64)> = lines
-        .iter()
-        .filter_map(|l| l.split(",").collect_tuple::<(&str, &str)>())
-        .map(|(x, y)| (x.parse().unwrap(), y.parse().unwrap()))
-        .collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let (points, folds) = parse_input(input)?;
 
-    let final_points = lines
-        .iter()
-        .skip(points.len() + 1)
-        .map(|l| parse_fold(l.as_str()).expect("failed to parse fold").1)
-        .fold(points, |points, (x_y, n)| {
-            points
-                .into_iter()
-                .map(|(x, y)| {
-                    let x = if x_y == 'x' && x > n { 2 * n - x } else { x };
-                    let y = if x_y == 'y' && y > n { 2 * n - y } else { y };
-                    (x, y)
-                })
-                .unique()
-                .collect_vec()
-        });
+    let final_points = folds.into_iter().fold(points, |points, (x_y, n)| {
+        points
+            .into_iter()
+            .map(|(x, y)| {
+                let x = if x_y == 'x' && x > n { 2 * n - x } else { x };
+                let y = if x_y == 'y' && y > n { 2 * n - y } else { y };
+                (x, y)
+            })
+            .unique()
+            .collect_vec()
+    });
 
-    print_board(&final_points);
+    Ok(Answer::Screen(render_board(&final_points)))
 }
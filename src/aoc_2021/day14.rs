@@ -1,11 +1,8 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn polymer_grow(
-    chain: HashMap<(u8, u8), usize>,
-    rules: &HashMap<(u8, u8), u8>,
-) -> HashMap<(u8, u8), usize> {
-    let mut new_chain: HashMap<(u8, u8), usize> = HashMap::new();
-    for ((c1, c2), count) in chain {
+fn polymer_grow(chain: &Chain, rules: &Rules) -> Chain {
+    let mut new_chain = Chain::new();
+    for (&(c1, c2), &count) in chain {
         if let Some(&c_mid) = rules.get(&(c1, c2)) {
             *new_chain.entry((c1, c_mid)).or_insert(0) += count;
             *new_chain.entry((c_mid, c2)).or_insert(0) += count;
@@ -16,41 +13,46 @@ fn polymer_grow(
     new_chain
 }
 
-fn input() -> (HashMap<(u8, u8), u8>, HashMap<(u8, u8), usize>) {
-    let lines = std_iter!(Lines).collect_vec();
-    let rules: HashMap<(u8, u8), u8> = lines[2..]
-        .iter()
-        .filter_map(|l| l.split(" -> ").collect_tuple::<(&str, &str)>())
-        .map(|(lhs, rhs)| ((lhs.as_bytes()[0], lhs.as_bytes()[1]), rhs.as_bytes()[0]))
-        .collect();
-    let template = format!("a{}a", &lines[0]).bytes().tuple_windows().counts();
-    (rules, template)
+type Rules = HashMap<(u8, u8), u8>;
+type Chain = HashMap<(u8, u8), usize>;
+
+/// A pair insertion rule like `CH -> B`.
+fn parse_rule(i: &str) -> ParseResult<'_, ((u8, u8), u8)> {
+    map_opt(
+        template::<(&str, char)>("{} -> {}"),
+        |(pair, element)| match pair.as_bytes() {
+            &[first, second] => Some(((first, second), element as u8)),
+            _ => None,
+        },
+    )(i)
 }
 
-pub fn part1() {
-    let (rules, template) = input();
-    let final_chain = (0..10).fold(template, |chain, _| polymer_grow(chain, &rules));
-    let mut counts = HashMap::new();
-    for ((c1, c2), count) in final_chain {
-        *counts.entry(c1).or_insert(0) += count;
-        *counts.entry(c2).or_insert(0) += count;
-    }
-    counts.remove(&b'a');
-    if let MinMaxResult::MinMax(min, max) = counts.values().minmax() {
-        println!("{}", (*max - *min) / 2);
-    }
+fn parse_input(input: &str) -> Result<(Rules, Chain), ParseError> {
+    let (template, rules) = parse_sections(input, (alpha1, lines_of(parse_rule)))?;
+    let template = format!("a{}a", template).bytes().tuple_windows().counts();
+    Ok((rules.into_iter().collect(), template))
 }
 
-pub fn part2() {
-    let (rules, template) = input();
-    let final_chain = (0..40).fold(template, |chain, _| polymer_grow(chain, &rules));
+/// The difference between the most and least common element after growing `steps` times.
+fn polymer_spread(input: &str, steps: usize) -> Result<usize, ParseError> {
+    let (rules, template) = parse_input(input)?;
+    let final_chain = (0..steps).fold(template, |chain, _| polymer_grow(&chain, &rules));
     let mut counts = HashMap::new();
     for ((c1, c2), count) in final_chain {
         *counts.entry(c1).or_insert(0) += count;
         *counts.entry(c2).or_insert(0) += count;
     }
     counts.remove(&b'a');
-    if let MinMaxResult::MinMax(min, max) = counts.values().minmax() {
-        println!("{}", (*max - *min) / 2);
+    match counts.values().minmax() {
+        MinMaxResult::MinMax(min, max) => Ok((*max - *min) / 2),
+        _ => unreachable!(),
     }
 }
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    Ok(polymer_spread(input, 10)?.into())
+}
+
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    Ok(polymer_spread(input, 40)?.into())
+}
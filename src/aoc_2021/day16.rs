@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 fn hex_to_binary(b: u8) -> String {
     let n = match b {
         b'0'..=b'9' => b - b'0',
@@ -40,7 +40,7 @@ fn parse_sub_packet(s: &str) -> (&str, Vec<Packet>) {
             let mut packets = Vec::new();
             let (s, bit_length) = parse_number(s, 15);
             let (s, mut s_packets) = take_bits(s, bit_length as usize);
-            while s_packets.len() > 0 {
+            while !s_packets.is_empty() {
                 let (s_packets_next, packet) = parse_packet(s_packets);
                 s_packets = s_packets_next;
                 packets.push(packet);
@@ -65,7 +65,7 @@ fn parse_packet(s: &str) -> (&str, Packet) {
     match type_id {
         4 => {
             let (s, value) = parse_literal_list(s);
-            (s, Packet::Literal(version, value as u64))
+            (s, Packet::Literal(version, value))
         }
         op => {
             let (s, sub_list) = parse_sub_packet(s);
@@ -95,15 +95,15 @@ fn packet_value(packet: &Packet) -> u64 {
                 3 => child_values.max().unwrap(),
                 5 => child_values
                     .collect_tuple()
-                    .and_then(|(p1, p2)| Some((p1 > p2) as u64))
+                    .map(|(p1, p2)| (p1 > p2) as u64)
                     .unwrap(),
                 6 => child_values
                     .collect_tuple()
-                    .and_then(|(p1, p2)| Some((p1 < p2) as u64))
+                    .map(|(p1, p2)| (p1 < p2) as u64)
                     .unwrap(),
                 7 => child_values
                     .collect_tuple()
-                    .and_then(|(p1, p2)| Some((p1 == p2) as u64))
+                    .map(|(p1, p2)| (p1 == p2) as u64)
                     .unwrap(),
                 _ => unreachable!(),
             }
@@ -111,14 +111,14 @@ fn packet_value(packet: &Packet) -> u64 {
     }
 }
 
-pub fn part1() {
-    let input: String = std_iter!(Bytes).map(hex_to_binary).collect();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let input: String = input.trimmed_bytes().map(hex_to_binary).collect();
     let (_, root) = parse_packet(&input);
-    println!("{:?}", version_sum(&root));
+    Ok((version_sum(&root)).into())
 }
 
-pub fn part2() {
-    let input: String = std_iter!(Bytes).map(hex_to_binary).collect();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let input: String = input.trimmed_bytes().map(hex_to_binary).collect();
     let (_, root) = parse_packet(&input);
-    println!("{:?}", packet_value(&root));
+    Ok((packet_value(&root)).into())
 }
@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::{debug_log, prelude::*};
 
 fn parse_target_area(s: &str) -> IResult<&str, (i64, i64, i64, i64)> {
     let (s, (x1, x2, y1, y2)) = tuple((
@@ -10,18 +10,15 @@ fn parse_target_area(s: &str) -> IResult<&str, (i64, i64, i64, i64)> {
     Ok((s, (x1, x2, y1, y2)))
 }
 
-pub fn part1() {
-    let (_, _, y1, y2) = std_iter!(Lines)
-        .map(|l| parse_target_area(&l).unwrap().1)
-        .next()
-        .unwrap();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let (_, _, y1, y2) = parse_all(input, parse_target_area)?;
     let vy_max = if y1 > 0 {
         y1.max(y2) + 1
     } else {
         -y1.min(y2) - 1
     };
     let y_max = (vy_max + 1) * vy_max / 2;
-    println!("{}", y_max);
+    Ok(y_max.into())
 }
 
 fn solve_quadratic(a: f64, b: f64, c: f64) -> Option<Vec<f64>> {
@@ -30,16 +27,13 @@ fn solve_quadratic(a: f64, b: f64, c: f64) -> Option<Vec<f64>> {
         let d_root = determinant.sqrt();
         Some(vec![(-b + d_root) / 2. / a, (-b - d_root) / 2. / a])
     } else {
-        eprintln!("No solution for: a={}, b={}, c={}", a, b, c);
+        debug_log!("No solution for: a={}, b={}, c={}", a, b, c);
         None
     }
 }
 
-pub fn part2() {
-    let (x1, x2, y1, y2) = std_iter!(Lines)
-        .map(|l| parse_target_area(&l).unwrap().1)
-        .next()
-        .unwrap();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let (x1, x2, y1, y2) = parse_all(input, parse_target_area)?;
 
     let vy_min = if y2 > 0 {
         solve_quadratic(1., 1., -2.0 * y1 as f64).unwrap()[0].ceil() as i64
@@ -75,5 +69,5 @@ pub fn part2() {
         })
         .count();
 
-    println!("{}", count);
+    Ok(count.into())
 }
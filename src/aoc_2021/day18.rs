@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn add_snailfish(fish_1: &[(u64, u64)], fish_2: &[(u64, u64)]) -> Vec<(u64, u64)> {
     let sum = fish_1
@@ -49,8 +49,9 @@ fn magnitude_snailfish(fish: &[(u64, u64)], level: u64) -> (&[(u64, u64)], u64)
     (fish, lhs * 3 + rhs * 2)
 }
 
-pub fn part1() {
-    let fish_sum = std_iter!(Lines)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let fish_sum = input
+        .lines()
         .map(|l| {
             let mut numbers = vec![];
             let mut level = 0u64;
@@ -67,11 +68,12 @@ pub fn part1() {
         .reduce(|f1, f2| add_snailfish(&f1, &f2))
         .unwrap();
     let (_, magnitude) = magnitude_snailfish(&fish_sum, 1);
-    println!("{}", magnitude);
+    Ok(magnitude.into())
 }
 
-pub fn part2() {
-    let fishes = std_iter!(Lines)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let fishes = input
+        .lines()
         .map(|l| {
             let mut numbers = vec![];
             let mut level = 0u64;
@@ -92,5 +94,5 @@ pub fn part2() {
         .map(|(f1, f2)| magnitude_snailfish(&add_snailfish(f1, f2), 1).1)
         .max()
         .unwrap();
-    println!("{}", max);
+    Ok(max.into())
 }
@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 #[derive(Debug)]
 struct Ship {
@@ -35,23 +35,24 @@ enum Instruction {
     Down(i64),
 }
 
-fn parse_instruction(line: &str) -> Instruction {
-    let (_, (instruction, step)) = separated_pair(
+fn parse_instruction(line: &str) -> IResult<&str, Instruction> {
+    let (line, (instruction, step)) = separated_pair(
         alt((tag("forward"), tag("down"), tag("up"))),
         char(' '),
         parse_i64,
-    )(line)
-    .expect("Parser Error");
+    )(line)?;
 
-    match instruction {
+    let instruction = match instruction {
         "forward" => Instruction::Forward(step),
         "up" => Instruction::Up(step),
         "down" => Instruction::Down(step),
         _ => unreachable!(),
-    }
+    };
+    Ok((line, instruction))
 }
-pub fn part1() {
-    let p = std_iter!(Lines).map(|s| parse_instruction(&s)).fold(
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let p = parse_lines(input, parse_instruction)?.into_iter().fold(
         Ship::default(),
         |ship, instruction| match instruction {
             Instruction::Up(n) => ship.move_y(-n),
@@ -59,11 +60,11 @@ pub fn part1() {
             Instruction::Forward(n) => ship.move_x(n),
         },
     );
-    println!("{}", p.x * p.y);
+    Ok((p.x * p.y).into())
 }
 
-pub fn part2() {
-    let p = std_iter!(Lines).map(|s| parse_instruction(&s)).fold(
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let p = parse_lines(input, parse_instruction)?.into_iter().fold(
         Ship::default(),
         |ship, instruction| match instruction {
             Instruction::Up(n) => ship.move_aim(-n),
@@ -71,5 +72,5 @@ pub fn part2() {
             Instruction::Forward(n) => ship.move_x(n).move_y(ship.aim * n),
         },
     );
-    println!("{}", p.x * p.y);
+    Ok((p.x * p.y).into())
 }
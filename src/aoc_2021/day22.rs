@@ -1,7 +1,53 @@
-pub fn part1() {
-    todo!()
+use crate::prelude::*;
+
+struct Step {
+    on: bool,
+    cuboid: Cuboid<3>,
+}
+
+/// Parses an inclusive `lo..hi` into a half-open range.
+fn parse_range(i: &str) -> ParserResult<'_, std::ops::Range<i64>> {
+    let (i, (lo, hi)) = separated_pair(parse_i64, tag(".."), parse_i64)(i)?;
+    Ok((i, lo..hi + 1))
+}
+
+fn parse_step(i: &str) -> ParserResult<'_, Step> {
+    let (i, on) = alt((tag("on"), tag("off")))(i)?;
+    let (i, x) = preceded(tag(" x="), parse_range)(i)?;
+    let (i, y) = preceded(tag(",y="), parse_range)(i)?;
+    let (i, z) = preceded(tag(",z="), parse_range)(i)?;
+    Ok((
+        i,
+        Step {
+            on: on == "on",
+            cuboid: Cuboid::new([x, y, z]),
+        },
+    ))
+}
+
+fn parse_input(i: &str) -> ParseResult<'_, Vec<Step>> {
+    separated_list1(tag("\n"), parse_step)(i)
+}
+
+fn reboot(steps: &[Step]) -> CuboidSet<3> {
+    let mut cubes = CuboidSet::new();
+    for step in steps {
+        if step.on {
+            cubes.insert(step.cuboid.clone());
+        } else {
+            cubes.remove(&step.cuboid);
+        }
+    }
+    cubes
+}
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let steps = parse_all(input, parse_input)?;
+    let initialization_area = Cuboid::new([-50..51, -50..51, -50..51]);
+    Ok(reboot(&steps).clip(&initialization_area).volume().into())
 }
 
-pub fn part2() {
-    todo!()
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let steps = parse_all(input, parse_input)?;
+    Ok(reboot(&steps).volume().into())
 }
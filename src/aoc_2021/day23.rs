@@ -1,7 +0,0 @@
-pub fn part1() {
-    todo!()
-}
-
-pub fn part2() {
-    todo!()
-}
@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn binary_to_dec(bits: &[u8]) -> u32 {
     bits.iter().fold(0u32, |n, b| (n << 1) + *b as u32)
@@ -8,10 +8,8 @@ fn parse_row_bits(row: &str) -> Vec<u8> {
     row.bytes().map(|c| c - b'0').collect_vec()
 }
 
-pub fn part1() {
-    let numbers = std_iter!(Lines)
-        .map(|s| parse_row_bits(&s))
-        .collect::<Vec<_>>();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let numbers = input.lines().map(parse_row_bits).collect::<Vec<_>>();
     let width = numbers[0].len();
     let height = numbers.len();
 
@@ -22,7 +20,7 @@ pub fn part1() {
 
     let epsilon = (!gamma) & ((1 << width) - 1);
 
-    println!("{}", gamma * epsilon)
+    Ok((gamma * epsilon).into())
 }
 
 fn filter_grid(
@@ -51,10 +49,8 @@ fn filter_grid(
     filter_grid(grid, remaining_rows, column + 1, filter_function)
 }
 
-pub fn part2() {
-    let grid = std_iter!(Lines)
-        .map(|line| parse_row_bits(&line))
-        .collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let grid = input.lines().map(parse_row_bits).collect_vec();
 
     let o2_row = filter_grid(
         &grid,
@@ -71,5 +67,5 @@ pub fn part2() {
 
     let o2_number = binary_to_dec(&grid[o2_row]);
     let co2_number = binary_to_dec(&grid[co2_row]);
-    println!("{}", o2_number * co2_number);
+    Ok((o2_number * co2_number).into())
 }
@@ -1,4 +1,24 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
+
+fn parse_board(i: &str) -> IResult<&str, Vec<u32>> {
+    let (i, rows) = lines_of(preceded(space0, separated_list1(space1, parse_int)))(i)?;
+    Ok((i, rows.concat()))
+}
+
+/// The numbers drawn, and every board's numbers one after another.
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let sections = sections(input);
+    let Some((draws, boards)) = sections.split_first() else {
+        return Err(ParseError::at(input, input, "the numbers drawn"));
+    };
+    let draws = finish(input, separated_list1(char(','), parse_int)(draws))?;
+    let boards = boards
+        .iter()
+        .map(|board| finish(input, parse_board(board)))
+        .collect::<Result<Vec<_>, _>>()?
+        .concat();
+    Ok((draws, boards))
+}
 
 fn has_bingo(board: &[u32]) -> bool {
     if board
@@ -12,25 +32,11 @@ fn has_bingo(board: &[u32]) -> bool {
     if (0usize..5).any(|c| (0..5).all(|r| board[r * 5 + c] == 0)) {
         return true;
     }
-    return false;
+    false
 }
 
-pub fn part1() {
-    let mut lines = std_iter!(Lines);
-    let num_sequence = lines
-        .next()
-        .unwrap()
-        .split(",")
-        .map(|string| string.parse::<u32>().unwrap())
-        .collect_vec();
-    let mut boards: Vec<u32> = lines
-        .map(|l| {
-            l.split(" ")
-                .filter_map(|l| l.parse::<u32>().ok())
-                .collect_vec()
-        })
-        .flatten()
-        .collect_vec();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let (num_sequence, mut boards) = parse_input(input)?;
 
     let board_count = boards.len() / 25;
     for n in num_sequence.into_iter() {
@@ -42,32 +48,15 @@ pub fn part1() {
 
         for board in 0..board_count {
             if has_bingo(&boards[board * 25..(board + 1) * 25]) {
-                println!(
-                    "{}",
-                    boards[board * 25..(board + 1) * 25].iter().sum::<u32>() * n
-                );
-                return;
+                return Ok((boards[board * 25..(board + 1) * 25].iter().sum::<u32>() * n).into());
             }
         }
     }
+    unreachable!()
 }
 
-pub fn part2() {
-    let mut lines = std_iter!(Lines);
-    let num_sequence = lines
-        .next()
-        .unwrap()
-        .split(",")
-        .map(|string| string.parse::<u32>().unwrap())
-        .collect_vec();
-    let mut boards: Vec<u32> = lines
-        .map(|l| {
-            l.split(" ")
-                .filter_map(|l| l.parse::<u32>().ok())
-                .collect_vec()
-        })
-        .flatten()
-        .collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let (num_sequence, mut boards) = parse_input(input)?;
 
     let board_count = boards.len() / 25;
     let mut remaining_boards = (0..board_count).collect_vec();
@@ -81,19 +70,16 @@ pub fn part2() {
 
         let boards_left = remaining_boards
             .iter()
-            .map(|board| *board)
+            .copied()
             .filter(|board| !has_bingo(&boards[board * 25..(board + 1) * 25]))
             .collect_vec();
 
-        if boards_left.len() == 0 {
+        if boards_left.is_empty() {
             let board = remaining_boards[0];
-            println!(
-                "{}",
-                boards[board * 25..(board + 1) * 25].iter().sum::<u32>() * n
-            );
-            return;
+            return Ok((boards[board * 25..(board + 1) * 25].iter().sum::<u32>() * n).into());
         }
 
         remaining_boards = boards_left;
     }
+    unreachable!()
 }
@@ -1,48 +1,31 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let overlapped_count = std_iter!(Lines)
-        .map(|l| {
-            let numbers = l
-                .split(" -> ")
-                .map(|l| l.split(",").map(|x| x.parse().unwrap()))
-                .flatten()
-                .collect_vec();
-            LineSegment::new(
-                Point::new(numbers[0], numbers[1]),
-                Point::new(numbers[2], numbers[3]),
-            )
-        })
-        .filter(|l| l.is_horizontal() || l.is_vertical())
-        .map(|l| l.scan_line())
-        .flatten()
-        .counts()
+fn parse_input(input: &str) -> Result<Vec<LineSegment>, ParseError> {
+    Ok(parse_lines(input, template("{},{} -> {},{}"))?
         .into_iter()
-        .filter(|(_, count)| *count >= 2)
-        .count();
+        .map(|(x1, y1, x2, y2)| LineSegment::new(Point2::new(x1, y1), Point2::new(x2, y2)))
+        .collect())
+}
 
-    println!("{:?}", overlapped_count);
+/// Counts the points covered by at least two lines, from where each pair of lines meets.
+fn count_overlapped(lines: &[LineSegment]) -> usize {
+    lines
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(a, b)| a.lattice_intersection(b))
+        .flat_map(|intersection| intersection.points())
+        .collect::<HashSet<_>>()
+        .len()
 }
 
-pub fn part2() {
-    let overlapped_count = std_iter!(Lines)
-        .map(|l| {
-            let numbers = l
-                .split(" -> ")
-                .map(|l| l.split(",").map(|x| x.parse().unwrap()))
-                .flatten()
-                .collect_vec();
-            LineSegment::new(
-                Point::new(numbers[0], numbers[1]),
-                Point::new(numbers[2], numbers[3]),
-            )
-        })
-        .map(|l| l.scan_line())
-        .flatten()
-        .counts()
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let lines = parse_input(input)?
         .into_iter()
-        .filter(|(_, count)| *count >= 2)
-        .count();
+        .filter(|l| l.is_horizontal() || l.is_vertical())
+        .collect_vec();
+    Ok(count_overlapped(&lines).into())
+}
 
-    println!("{:?}", overlapped_count);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    Ok(count_overlapped(&parse_input(input)?).into())
 }
@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn fishes_reproduction(fishes: Vec<usize>, days: usize) -> usize {
     (0..days)
@@ -19,24 +19,26 @@ fn fishes_reproduction(fishes: Vec<usize>, days: usize) -> usize {
         .sum()
 }
 
-pub fn part1() {
-    let fishes = std_iter!(Bytes)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let fishes = input
+        .bytes()
         .filter(|c| c.is_dec_digit())
         .map(|c| c - b'0')
         .fold(vec![0; 9], |mut v, d| {
             v[d as usize] += 1;
             v
         });
-    println!("{}", fishes_reproduction(fishes, 80));
+    Ok((fishes_reproduction(fishes, 80)).into())
 }
 
-pub fn part2() {
-    let fishes = std_iter!(Bytes)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let fishes = input
+        .bytes()
         .filter(|c| c.is_dec_digit())
         .map(|c| c - b'0')
         .fold(vec![0; 9], |mut v, d| {
             v[d as usize] += 1;
             v
         });
-    println!("{}", fishes_reproduction(fishes, 256));
+    Ok((fishes_reproduction(fishes, 256)).into())
 }
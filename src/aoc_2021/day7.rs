@@ -1,19 +1,18 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let numbers = std_iter!(SplitBy ",")
-        .map(|x| x.parse::<i64>().unwrap())
-        .sorted()
-        .collect_vec();
+fn parse_positions(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_all(input, separated_list1(tag(","), parse_i64))
+}
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let numbers = parse_positions(input)?.into_iter().sorted().collect_vec();
     let median = numbers[numbers.len() / 2];
     let total_diff: i64 = numbers.iter().map(|&x| (x - median).abs()).sum();
-    println!("{}", total_diff);
+    Ok(total_diff.into())
 }
 
-pub fn part2() {
-    let numbers = std_iter!(SplitBy ",")
-        .map(|x| x.parse::<i64>().unwrap())
-        .collect_vec();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let numbers = parse_positions(input)?;
 
     let mean = numbers.iter().sum::<i64>() as f64 / numbers.len() as f64;
 
@@ -22,5 +21,5 @@ pub fn part2() {
         .map(|i| numbers.iter().map(|&n| sum_to_1((n - i).abs())).sum())
         .min()
         .unwrap();
-    println!("{}", total_diff);
+    Ok(total_diff.into())
 }
@@ -1,18 +1,20 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-pub fn part1() {
-    let count = std_iter!(Lines)
-        .map(|l| {
-            let (_, rhs) = l.split_once(" | ").unwrap();
+/// The signal patterns and output digits of each display.
+fn parse_entries(input: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+    parse_lines(input, template("{} | {}"))
+}
+
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let count = parse_entries(input)?
+        .into_iter()
+        .map(|(_, rhs)| {
             rhs.split_whitespace()
-                .filter(|l| match l.len() {
-                    2 | 3 | 4 | 7 => true,
-                    _ => false,
-                })
+                .filter(|l| matches!(l.len(), 2 | 3 | 4 | 7))
                 .count()
         })
         .sum::<usize>();
-    println!("{}", count);
+    Ok(count.into())
 }
 
 fn encode_char(s: u8) -> u8 {
@@ -31,11 +33,10 @@ where
     vec.remove(x)
 }
 
-pub fn part2() {
-    let result = std_iter!(Lines)
-        .map(|l| {
-            let (lhs, rhs) = l.split_once(" | ").unwrap();
-
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let result = parse_entries(input)?
+        .into_iter()
+        .map(|(lhs, rhs)| {
             let mut patterns = lhs
                 .split_whitespace()
                 .map(|l| encode_lcd(l.as_bytes()))
@@ -74,5 +75,5 @@ pub fn part2() {
         })
         .sum::<usize>();
 
-    println!("{}", result);
+    Ok(result.into())
 }
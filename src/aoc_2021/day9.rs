@@ -1,50 +1,36 @@
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn make_volcano_grid() -> Vec<Vec<u8>> {
-    std_iter!(Lines)
-        .map(|l| l.as_bytes().into_iter().map(|&b| b - b'0').collect_vec())
-        .collect_vec()
+fn make_volcano_grid(input: &Input) -> Result<Grid<u8>, ParseError> {
+    input.grid_of(|b| b - b'0')
 }
 
-pub fn part1() {
-    let grid = make_volcano_grid();
-    let height = grid.len();
-    let width = grid[0].len();
-    let total = (0..height)
-        .cartesian_product(0..width)
-        .filter(|&(y, x)| {
-            make_neighbors(y, x, width, height).all(|(ny, nx)| grid[ny][nx] > grid[y][x])
-        })
-        .map(|(x, y)| grid[x][y] as u32 + 1)
-        .sum::<u32>();
-    println!("{}", total);
+fn low_points(grid: &Grid<u8>) -> impl Iterator<Item = Coord> + '_ {
+    grid.coords()
+        .filter(|&coord| grid.neighbors4(coord).all(|n| grid[n] > grid[coord]))
 }
 
-pub fn part2() {
-    let grid = make_volcano_grid();
-    let height = grid.len();
-    let width = grid[0].len();
-    let low_points = (0..height)
-        .cartesian_product(0..width)
-        .filter(|&(y, x)| {
-            make_neighbors(y, x, width, height).all(|(ny, nx)| grid[ny][nx] > grid[y][x])
-        })
-        .collect_vec();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let grid = make_volcano_grid(input)?;
+    let total = low_points(&grid)
+        .map(|coord| grid[coord] as u32 + 1)
+        .sum::<u32>();
+    Ok(total.into())
+}
 
-    let product = low_points
-        .into_iter()
-        .map(|(y, x)| {
-            let mut stack = vec![(y, x)];
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let grid = make_volcano_grid(input)?;
+    let product = low_points(&grid)
+        .map(|low_point| {
+            let mut stack = vec![low_point];
             let mut visited = HashSet::new();
-            while let Some((y, x)) = stack.pop() {
-                if !visited.insert((y, x)) {
+            while let Some(coord) = stack.pop() {
+                if !visited.insert(coord) {
                     continue;
                 }
-                make_neighbors(y, x, width, height)
-                    .filter(|&(ny, nx)| grid[ny][nx] != 9 && grid[ny][nx] > grid[y][x])
-                    .for_each(|node| {
-                        stack.push(node);
-                    });
+                stack.extend(
+                    grid.neighbors4(coord)
+                        .filter(|&n| grid[n] != 9 && grid[n] > grid[coord]),
+                );
             }
             visited
         })
@@ -54,5 +40,5 @@ pub fn part2() {
         .take(3)
         .product::<usize>();
 
-    println!("{}", product);
+    Ok(product.into())
 }
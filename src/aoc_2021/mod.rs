@@ -8,14 +8,8 @@ pub mod day15;
 pub mod day16;
 pub mod day17;
 pub mod day18;
-pub mod day19;
 pub mod day2;
-pub mod day20;
-pub mod day21;
 pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
 pub mod day3;
 pub mod day4;
 pub mod day5;
@@ -24,30 +18,32 @@ pub mod day7;
 pub mod day8;
 pub mod day9;
 
-pub const SOLUTIONS: [[fn() -> (); 2]; 25] = [
-    [day1::part1, day1::part2],
-    [day2::part1, day2::part2],
-    [day3::part1, day3::part2],
-    [day4::part1, day4::part2],
-    [day5::part1, day5::part2],
-    [day6::part1, day6::part2],
-    [day7::part1, day7::part2],
-    [day8::part1, day8::part2],
-    [day9::part1, day9::part2],
-    [day10::part1, day10::part2],
-    [day11::part1, day11::part2],
-    [day12::part1, day12::part2],
-    [day13::part1, day13::part2],
-    [day14::part1, day14::part2],
-    [day15::part1, day15::part2],
-    [day16::part1, day16::part2],
-    [day17::part1, day17::part2],
-    [day18::part1, day18::part2],
-    [day19::part1, day19::part2],
-    [day20::part1, day20::part2],
-    [day21::part1, day21::part2],
-    [day22::part1, day22::part2],
-    [day23::part1, day23::part2],
-    [day24::part1, day24::part2],
-    [day25::part1, day25::part2],
+use crate::answer::Solution;
+
+pub const SOLUTIONS: [[Option<Solution>; 2]; 25] = [
+    [Some(day1::part1), Some(day1::part2)],
+    [Some(day2::part1), Some(day2::part2)],
+    [Some(day3::part1), Some(day3::part2)],
+    [Some(day4::part1), Some(day4::part2)],
+    [Some(day5::part1), Some(day5::part2)],
+    [Some(day6::part1), Some(day6::part2)],
+    [Some(day7::part1), Some(day7::part2)],
+    [Some(day8::part1), Some(day8::part2)],
+    [Some(day9::part1), Some(day9::part2)],
+    [Some(day10::part1), Some(day10::part2)],
+    [Some(day11::part1), Some(day11::part2)],
+    [Some(day12::part1), Some(day12::part2)],
+    [Some(day13::part1), Some(day13::part2)],
+    [Some(day14::part1), Some(day14::part2)],
+    [Some(day15::part1), Some(day15::part2)],
+    [Some(day16::part1), Some(day16::part2)],
+    [Some(day17::part1), Some(day17::part2)],
+    [Some(day18::part1), Some(day18::part2)],
+    [None, None],
+    [None, None],
+    [None, None],
+    [Some(day22::part1), Some(day22::part2)],
+    [None, None],
+    [None, None],
+    [None, None],
 ];
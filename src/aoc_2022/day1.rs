@@ -1,26 +1,22 @@
-use crate::std_iter;
+use crate::prelude::*;
 
-fn input() -> Vec<u64> {
-    std_iter!(Lines)
-        .fold(vec![0u64], |mut queue, line| {
-            if line.is_empty() {
-                queue.push(0);
-            } else {
-                *queue.last_mut().unwrap() += line.parse::<u64>().unwrap();
-            }
-            queue
-        })
+/// The calories each elf carries.
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    Ok(parse_each_section(input, lines_of(parse_u64))?
+        .into_iter()
+        .map(|items| items.into_iter().sum())
+        .collect())
 }
 
-pub fn part1() {
-    let max = input().into_iter().max().unwrap();
-    println!("{}", max);
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let max = parse_input(input)?.into_iter().max().unwrap();
+    Ok(max.into())
 }
 
-pub fn part2() {
-    let mut sums = input();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut sums = parse_input(input)?;
     sums.sort();
     let three = &sums[sums.len() - 3..];
 
-    println!("{}", three.iter().sum::<u64>());
+    Ok((three.iter().sum::<u64>()).into())
 }
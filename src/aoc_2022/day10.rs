@@ -1,4 +1,4 @@
-use crate::{prelude::*, std_iter};
+use crate::{debug_log, prelude::*};
 
 #[derive(Clone, Copy, Debug)]
 enum Instruction {
@@ -8,8 +8,8 @@ enum Instruction {
 
 fn parse_instruction(i: &str) -> IResult<&str, Instruction> {
     alt((
-        |i| preceded(tag("addx "), parse_i64)(i).and_then(|(i, v)| Ok((i, Instruction::Addx(v)))),
-        |i| tag("noop")(i).and_then(|(i, _)| Ok((i, Instruction::Noop))),
+        |i| preceded(tag("addx "), parse_i64)(i).map(|(i, v)| (i, Instruction::Addx(v))),
+        |i| tag("noop")(i).map(|(i, _)| (i, Instruction::Noop)),
     ))(i)
 }
 
@@ -77,14 +77,14 @@ impl MachineState {
     }
 }
 
-pub fn part1() {
-    let mut instructions = std_iter!(Lines).map(|l| parse_instruction(&l).unwrap().1);
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let mut instructions = parse_lines(input, parse_instruction)?.into_iter();
     let mut machine = MachineState::new();
     let mut cycle = 0;
     let mut sum = 0;
     loop {
         cycle += 1;
-        eprintln!("cycle {}, x={}", cycle, machine.x);
+        debug_log!("cycle {}, x={}", cycle, machine.x);
         if (cycle - 20) % 40 == 0 {
             sum += cycle * machine.x;
         }
@@ -92,11 +92,11 @@ pub fn part1() {
             break;
         }
     }
-    println!("{}", sum);
+    Ok(sum.into())
 }
 
-pub fn part2() {
-    let mut instructions = std_iter!(Lines).map(|l| parse_instruction(&l).unwrap().1);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut instructions = parse_lines(input, parse_instruction)?.into_iter();
     let mut machine = MachineState::new();
     let mut cycle = 0;
     let mut screen = vec![vec!['.'; 40]; 6];
@@ -114,10 +114,7 @@ pub fn part2() {
             break;
         }
     }
-    for row in screen {
-        for c in row {
-            print!("{}", c);
-        }
-        println!("");
-    }
+    Ok(Answer::Screen(
+        screen.into_iter().map(String::from_iter).collect(),
+    ))
 }
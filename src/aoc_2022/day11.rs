@@ -1,17 +1,31 @@
-use nom::character::complete::one_of;
-
 use crate::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, AocParse)]
+enum Operator {
+    #[aoc(format = "+")]
+    Add,
+    #[aoc(format = "*")]
+    Multiply,
+}
+
+#[derive(Debug, AocParse)]
 enum Operand {
+    #[aoc(format = "{}")]
     Number(u64),
+    #[aoc(format = "old")]
     This,
 }
 
-#[derive(Debug)]
+#[derive(Debug, AocParse)]
+#[aoc(format = "Monkey {_}:
+  Starting items: {items}
+  Operation: new = old {operator} {operand}
+  Test: divisible by {predicate}
+    If true: throw to monkey {target_true}
+    If false: throw to monkey {target_false}")]
 struct Monkey {
     items: Vec<u64>,
-    operator: char,
+    operator: Operator,
     operand: Operand,
     predicate: u64,
     target_true: usize,
@@ -20,7 +34,7 @@ struct Monkey {
 
 impl Monkey {
     fn calculate_target(&self, anxiety: u64) -> usize {
-        if anxiety % self.predicate == 0 {
+        if anxiety.is_multiple_of(self.predicate) {
             self.target_true
         } else {
             self.target_false
@@ -33,102 +47,39 @@ impl Monkey {
             Operand::This => n,
         };
         match self.operator {
-            '+' => n + operand,
-            '*' => n * operand,
-            _ => unreachable!(),
+            Operator::Add => n + operand,
+            Operator::Multiply => n * operand,
         }
     }
 }
 
-fn parse_operand(i: &str) -> ParseResult<Operand> {
-    parse_u64(i)
-        .and_then(|(i, n)| Ok((i, Operand::Number(n))))
-        .or_else(|_| tag("old")(i).and_then(|(i, _)| Ok((i, Operand::This))))
-}
-
-fn parse_monkey(i: &str) -> ParseResult<Monkey> {
-    let (i, _) = tuple((tag("Monkey "), digit1, tag(":\n")))(i)?;
-    let (i, items) = preceded(
-        tag("  Starting items: "),
-        separated_list1(tag(", "), parse_u64),
-    )(i)?;
-    let (i, _) = tag("\n")(i)?;
-    let (i, (operator, operand)) = preceded(
-        tag("  Operation: new = old "),
-        separated_pair(one_of("+-*/"), tag(" "), parse_operand),
-    )(i)?;
-    let (i, _) = tag("\n")(i)?;
-    let (i, predicate) = preceded(tag("  Test: divisible by "), parse_u64)(i)?;
-    let (i, _) = tag("\n")(i)?;
-    let (i, target_true) = preceded(tag("    If true: throw to monkey "), parse_usize)(i)?;
-    let (i, _) = tag("\n")(i)?;
-    let (i, target_false) = preceded(tag("    If false: throw to monkey "), parse_usize)(i)?;
-    let (i, _) = take_while(|c| c == '\n')(i)?;
-    Ok((
-        i,
-        Monkey {
-            items,
-            operator,
-            operand,
-            predicate,
-            target_true,
-            target_false,
-        },
-    ))
+fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    parse_each_section(input, Monkey::parse)
 }
 
-fn input() -> Vec<Monkey> {
-    let mut line = String::new();
-    std::io::stdin()
-        .read_to_string(&mut line)
-        .expect("Failed to read");
-    let mut monkeys = vec![];
-    let mut i = line.as_str();
-
-    while let Ok((next_i, monkey)) = parse_monkey(i) {
-        i = next_i;
-        monkeys.push(monkey);
-    }
-    monkeys
-}
-
-pub fn part1() {
-    let mut monkeys = input();
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: impl Fn(u64) -> u64) -> usize {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
     let mut counts = vec![0; monkeys.len()];
-    for _ in 0..20 {
-        for i in 0..monkeys.len() {
-            counts[i] += monkeys[i].items.len();
-            std::mem::take(&mut monkeys[i].items)
-                .into_iter()
-                .for_each(|n| {
-                    let anxiety = monkeys[i].calculate_anxiety(n);
-                    let anxiety = anxiety / 3;
-                    let target = monkeys[i].calculate_target(anxiety);
-                    monkeys[target].items.push(anxiety);
-                })
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            counts[i] += items[i].len();
+            for n in std::mem::take(&mut items[i]) {
+                let anxiety = relief(monkey.calculate_anxiety(n));
+                items[monkey.calculate_target(anxiety)].push(anxiety);
+            }
         }
     }
     counts.sort();
-    println!("{}", counts[counts.len() - 1] * counts[counts.len() - 2]);
+    counts[counts.len() - 1] * counts[counts.len() - 2]
 }
 
-pub fn part2() {
-    let mut monkeys = input();
-    let mut counts = vec![0; monkeys.len()];
-    let multiple : u64 = monkeys.iter().map(|m| m.predicate).product();
-    for _ in 0..10000 {
-        for i in 0..monkeys.len() {
-            counts[i] += monkeys[i].items.len();
-            std::mem::take(&mut monkeys[i].items)
-                .into_iter()
-                .for_each(|n| {
-                    let anxiety = monkeys[i].calculate_anxiety(n);
-                    let anxiety = anxiety % multiple;
-                    let target = monkeys[i].calculate_target(anxiety);
-                    monkeys[target].items.push(anxiety);
-                })
-        }
-    }
-    counts.sort();
-    println!("{}", counts[counts.len() - 1] * counts[counts.len() - 2]);
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let monkeys = parse_input(input)?;
+    Ok(monkey_business(&monkeys, 20, |anxiety| anxiety / 3).into())
+}
+
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let monkeys = parse_input(input)?;
+    let multiple = math::lcm_of(monkeys.iter().map(|m| m.predicate));
+    Ok(monkey_business(&monkeys, 10000, |anxiety| anxiety % multiple).into())
 }
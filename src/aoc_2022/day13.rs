@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use nom::combinator::map;
 
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 #[derive(Clone)]
 enum Packet {
@@ -10,22 +10,22 @@ enum Packet {
     List(Vec<Packet>),
 }
 
-fn parse_list(s: &str) -> ParseResult<Vec<Packet>> {
+fn parse_list(s: &str) -> ParseResult<'_, Vec<Packet>> {
     delimited(tag("["), separated_list0(tag(","), parse_packet), tag("]"))(s)
 }
 
-fn parse_packet(i: &str) -> ParseResult<Packet> {
+fn parse_packet(i: &str) -> ParseResult<'_, Packet> {
     alt((
-        map(parse_list, |l| Packet::List(l)),
-        map(parse_u64, |n| Packet::Number(n)),
+        map(parse_list, Packet::List),
+        map(parse_u64, Packet::Number),
     ))(i)
 }
 
-fn parse_pair(i: &str) -> ParseResult<(Packet, Packet)> {
+fn parse_pair(i: &str) -> ParseResult<'_, (Packet, Packet)> {
     separated_pair(parse_packet, tag("\n"), parse_packet)(i)
 }
 
-fn parse_packet_pair_list(i: &str) -> ParseResult<Vec<(Packet, Packet)>> {
+fn parse_packet_pair_list(i: &str) -> ParseResult<'_, Vec<(Packet, Packet)>> {
     separated_list1(tag("\n\n"), parse_pair)(i)
 }
 
@@ -47,10 +47,8 @@ impl Packet {
     }
 }
 
-pub fn part1() {
-    let packets = parse_packet_pair_list(std_iter!(OneString).as_str())
-        .unwrap()
-        .1;
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let packets = parse_all(input, parse_packet_pair_list)?;
 
     let result: usize = packets
         .iter()
@@ -59,18 +57,18 @@ pub fn part1() {
         .map(|(i, _)| i + 1)
         .sum();
 
-    println!("{}", result);
+    Ok(result.into())
 }
 
-fn parse_packet_list(i: &str) -> ParserResult<Vec<Packet>> {
+fn parse_packet_list(i: &str) -> ParserResult<'_, Vec<Packet>> {
     separated_list1(many1(tag("\n")), parse_packet)(i)
 }
 
-pub fn part2() {
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
     let distress_a = Packet::List(vec![Packet::List(vec![Packet::Number(2)])]);
     let distress_b = Packet::List(vec![Packet::List(vec![Packet::Number(6)])]);
 
-    let mut packets = parse_packet_list(std_iter!(OneString).as_str()).unwrap().1;
+    let mut packets = parse_all(input, parse_packet_list)?;
     packets.push(distress_a.clone());
     packets.push(distress_b.clone());
     packets.sort_by(|a, b| a.cmp(b));
@@ -81,5 +79,5 @@ pub fn part2() {
     let b = packets
         .binary_search_by(|probe| probe.cmp(&distress_b))
         .unwrap();
-    println!("{}", (a + 1) * (b + 1));
+    Ok(((a + 1) * (b + 1)).into())
 }
@@ -1,97 +1,79 @@
 use crate::prelude::*;
 
-fn parse_point(i: &str) -> ParseResult<(i64, i64)> {
-    separated_pair(parse_i64, tag(","), parse_i64)(i)
+const SOURCE: Point2 = Point2::new(500, 0);
+
+/// Where sand tries to go, in order, with `y` growing downwards.
+const FALLS: [Point2; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+fn parse_point(i: &str) -> ParseResult<'_, Point2> {
+    let (i, (x, y)) = separated_pair(parse_i64, tag(","), parse_i64)(i)?;
+    Ok((i, Point2::new(x, y)))
 }
 
-fn parse_path(i: &str) -> ParseResult<Vec<(i64, i64)>> {
+fn parse_path(i: &str) -> ParseResult<'_, Vec<Point2>> {
     separated_list1(tag(" -> "), parse_point)(i)
 }
 
-fn parse_input(i: &str) -> ParseResult<Vec<Vec<(i64, i64)>>> {
-    separated_list1(tag("\n"), parse_path)(i)
+fn parse_input(input: &str) -> Result<Vec<Vec<Point2>>, ParseError> {
+    parse_lines(input, parse_path)
 }
 
-fn scanline(start: (i64, i64), end: (i64, i64)) -> Vec<(i64, i64)> {
-    if end.0 > start.0 {
-        return (start.0..=end.0).map(|x| (x, start.1)).collect();
-    }
-    if end.0 < start.0 {
-        return (end.0..=start.0).map(|x| (x, start.1)).collect();
-    }
-    if end.1 > start.1 {
-        return (start.1..=end.1).map(|y| (start.0, y)).collect();
-    }
-    if end.1 < start.1 {
-        return (end.1..=start.1).map(|y| (start.0, y)).collect();
-    }
-    unreachable!()
-}
-
-fn sand_drop(x: i64, y: i64, bottom: i64, occupied: &HashSet<(i64, i64)>) -> (i64, i64) {
-    if y == bottom {
-        return (x, y);
-    }
-    if !occupied.contains(&(x, y + 1)) {
-        return sand_drop(x, y + 1, bottom, occupied);
-    }
-    if !occupied.contains(&(x - 1, y + 1)) {
-        return sand_drop(x - 1, y + 1, bottom, occupied);
-    }
-    if !occupied.contains(&(x + 1, y + 1)) {
-        return sand_drop(x + 1, y + 1, bottom, occupied);
+fn sand_drop(mut sand: Point2, bottom: i64, occupied: &HashSet<Point2>) -> Point2 {
+    while sand.y < bottom {
+        match FALLS
+            .iter()
+            .map(|&fall| sand + fall)
+            .find(|next| !occupied.contains(next))
+        {
+            Some(next) => sand = next,
+            None => break,
+        }
     }
-    return (x, y);
+    sand
 }
 
-pub fn part1() {
-    let mut occupied: HashSet<(i64, i64)> = parse_input(stdio_string().as_str())
-        .unwrap()
-        .1
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let mut occupied: HashSet<Point2> = parse_input(input)?
         .into_iter()
         .flat_map(|path| {
-            (0..path.len() - 1)
-                .flat_map(|i| scanline(path[i], path[i + 1]).into_iter())
-                .collect_vec()
-                .into_iter()
+            path.into_iter()
+                .tuple_windows()
+                .flat_map(|(start, end)| LineSegment::new(start, end).points())
         })
         .collect();
 
-    let bottom = occupied.iter().map(|(_, y)| *y).max().unwrap();
+    let bottom = occupied.iter().map(|p| p.y).max().unwrap();
 
     for count in 0.. {
-        let sand = sand_drop(500, 0, bottom, &occupied);
-        if sand.1 < bottom {
+        let sand = sand_drop(SOURCE, bottom, &occupied);
+        if sand.y < bottom {
             occupied.insert(sand);
         } else {
-            println!("{}", count);
-            break;
+            return Ok(count.into());
         }
     }
+    unreachable!()
 }
 
-pub fn part2() {
-    let mut occupied: HashSet<(i64, i64)> = parse_input(stdio_string().as_str())
-        .unwrap()
-        .1
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let mut occupied: HashSet<Point2> = parse_input(input)?
         .into_iter()
         .flat_map(|path| {
-            (0..path.len() - 1)
-                .flat_map(|i| scanline(path[i], path[i + 1]).into_iter())
-                .collect_vec()
-                .into_iter()
+            path.into_iter()
+                .tuple_windows()
+                .flat_map(|(start, end)| LineSegment::new(start, end).points())
         })
         .collect();
 
-    let bottom = occupied.iter().map(|(_, y)| *y).max().unwrap() + 1;
+    let bottom = occupied.iter().map(|p| p.y).max().unwrap() + 1;
 
     for count in 0.. {
-        let sand = sand_drop(500, 0, bottom, &occupied);
-        if sand == (500, 0) {
-            println!("{}", count + 1);
-            break;
+        let sand = sand_drop(SOURCE, bottom, &occupied);
+        if sand == SOURCE {
+            return Ok((count + 1).into());
         } else {
             occupied.insert(sand);
         }
     }
+    unreachable!()
 }
@@ -1,89 +1,71 @@
+use std::ops::Range;
+
+use nom::combinator::map;
+
 use crate::prelude::*;
 
 struct Report {
-    sensor: (i64, i64), 
-    beacon: (i64, i64)
+    sensor: Point2,
+    beacon: Point2,
 }
 
 impl Report {
     fn distance(&self) -> i64 {
-        (self.beacon.0 - self.sensor.0).abs() + (self.beacon.1 - self.sensor.1).abs()
+        self.sensor.manhattan(self.beacon)
     }
 }
 
-fn parse_line(i: &str) -> ParserResult<Report> {
-    let (i, x) = preceded(tag("Sensor at x="), parse_i64)(i)?;
-    let (i, y) = preceded(tag(", y="), parse_i64)(i)?;
-    let (i, x_) = preceded(tag(": closest beacon is at x="), parse_i64)(i)?;
-    let (i, y_) = preceded(tag(", y="), parse_i64)(i)?;
-
-    Ok((i, Report { sensor: (x, y), beacon: (x_, y_)}))
+fn parse_input(i: &str) -> ParseResult<'_, Vec<Report>> {
+    let line =
+        template::<(i64, i64, i64, i64)>("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+    let report = map(line, |(x, y, x_, y_)| Report {
+        sensor: Point2::new(x, y),
+        beacon: Point2::new(x_, y_),
+    });
+    separated_list1(tag("\n"), report)(i)
 }
 
-fn parse_input(i: &str) -> ParseResult<Vec<Report>> {
-    separated_list1(tag("\n"), parse_line)(i)
-}
-
-// const TARGET_ROW: i64 = 10;
 const TARGET_ROW: i64 = 2_000_000;
-
-// const CONSTRAINT: i64 = 20;
 const CONSTRAINT: i64 = 4_000_000;
 
-fn merge_ranges(mut ranges: impl Iterator<Item=(i64, i64)>) -> Vec<(i64, i64)> {
-    let mut merged_dead_zones = Vec::new();
-    if let Some(first) = ranges.next() {
-        merged_dead_zones.push(first);
-    }
-
-    for (lo, hi)  in ranges {
-        let (lo_prev, hi_prev) = merged_dead_zones.pop().unwrap();
-        if lo <= hi_prev {
-            merged_dead_zones.push((lo_prev, hi.max(hi_prev)))
-        } else {
-            merged_dead_zones.push((lo_prev, hi_prev));
-            merged_dead_zones.push((lo, hi));
-        }
-    }
-    merged_dead_zones
+/// The columns of `row` that the sensor rules out, possibly empty.
+fn dead_zone(report: &Report, row: i64) -> Range<i64> {
+    let horizontal_max_delta = report.distance() - (report.sensor.y - row).abs();
+    report.sensor.x - horizontal_max_delta..report.sensor.x + horizontal_max_delta + 1
 }
 
-fn dead_zone(report: &Report, row: i64) -> Option<(i64, i64)> {
-    let distance = report.distance();
-    let horizontal_max_delta = distance - (report.sensor.1 - row).abs();
-    if horizontal_max_delta < 0 {
-        None
-    } else {
-        let range = (report.sensor.0 - horizontal_max_delta, report.sensor.0 + horizontal_max_delta + 1);
-        Some(range)
-    }
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    part1_at(input, TARGET_ROW)
 }
 
-pub fn part1() {
-    let reports = parse_input(stdio_string().as_str()).unwrap().1;
+/// Part 1 on a given row, which the puzzle example sets differently.
+pub fn part1_at(input: &Input, target_row: i64) -> Result<Answer, ParseError> {
+    let reports = parse_all(input, parse_input)?;
+    let dead_zones: RangeSet = reports.iter().map(|r| dead_zone(r, target_row)).collect();
 
-    let dead_zones = reports.iter().filter_map(|r| dead_zone(r, TARGET_ROW)).sorted();
-    let merged_dead_zones = merge_ranges(dead_zones);
-
-    let existing = reports.iter().filter(|r| r.beacon.1 == TARGET_ROW).map(|r| r.beacon.0).collect::<HashSet::<_>>().len();
-    let total: i64 = merged_dead_zones.into_iter().map(|(lo, hi)| hi - lo).sum();
-    println!("{}", total as usize - existing);
+    let existing = reports
+        .iter()
+        .filter(|r| r.beacon.y == target_row)
+        .map(|r| r.beacon.x)
+        .collect::<HashSet<_>>()
+        .len();
+    Ok((dead_zones.len() as usize - existing).into())
 }
 
-pub fn part2() {
-    let reports = parse_input(stdio_string().as_str()).unwrap().1;
-    for y in 0..=CONSTRAINT {
-        let dead_zones = reports.iter().filter_map(|r| dead_zone(r, y))
-            .map(|(lo, hi)| (lo.clamp(0, CONSTRAINT), hi.clamp(0, CONSTRAINT)))
-            .sorted();
-        let merged_dead_zones = merge_ranges(dead_zones);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    part2_at(input, CONSTRAINT)
+}
 
-        if merged_dead_zones.len() == 1 {
-            continue;
+/// Part 2 with the beacon within `0..=constraint` on both axes, which the puzzle example
+/// sets differently.
+pub fn part2_at(input: &Input, constraint: i64) -> Result<Answer, ParseError> {
+    let reports = parse_all(input, parse_input)?;
+    let area = RangeSet::from(0..constraint + 1);
+    for y in 0..=constraint {
+        let dead_zones: RangeSet = reports.iter().map(|r| dead_zone(r, y)).collect();
+        if let Some(gap) = area.difference(&dead_zones).ranges().first() {
+            return Ok((gap.start * 4000000 + y).into());
         }
-        let x = merged_dead_zones[0].1;
-        println!("{}", x * 4000000 + y);
-        return;
     }
-    unreachable!();
-}
\ No newline at end of file
+    unreachable!()
+}
@@ -1,5 +1,4 @@
 use crate::prelude::*;
-use std::hash::Hash;
 
 struct Node<'a> {
     name: &'a str,
@@ -7,28 +6,20 @@ struct Node<'a> {
     rate: u64,
 }
 
-struct Graph<'a> {
-    valves: HashMap<&'a str, Node<'a>>,
-    distances: HashMap<(&'a str, &'a str), u64>,
+struct Tunnels {
+    rates: Vec<u64>,
+    /// The minutes it takes to walk between any two valves.
+    distances: Vec<Vec<u64>>,
 }
 
-impl<'a> Graph<'a> {
-    fn get_valve(&self, name: &'a str) -> &'a Node {
-        self.valves.get(name)
-            .expect(&format!("Has no valve named {}", name))
-    }
-
-    fn get_distance(&self, x: &str, y: &str) -> u64 {
-        *self.distances.get(&(x, y))
-            .expect(&format!("Cannot get distance between {}, and {}", x, y))
-    }
-}
-
-fn parse_line(i: &str) -> ParseResult<Node> {
+fn parse_line(i: &str) -> ParseResult<'_, Node<'_>> {
     let (i, name) = preceded(tag("Valve "), alpha1)(i)?;
     let (i, rate) = preceded(tag(" has flow rate="), parse_u64)(i)?;
     let (i, children) = preceded(
-        tag("; tunnels lead to valves "),
+        alt((
+            tag("; tunnels lead to valves "),
+            tag("; tunnel leads to valve "),
+        )),
         separated_list0(tag(", "), alpha1),
     )(i)?;
     Ok((
@@ -41,88 +32,54 @@ fn parse_line(i: &str) -> ParseResult<Node> {
     ))
 }
 
-fn parse_input(i: &str) -> ParserResult<Vec<Node>> {
+fn parse_input(i: &str) -> ParserResult<'_, Vec<Node<'_>>> {
     separated_list1(tag("\n"), parse_line)(i)
 }
 
-fn generate_graph(valves: Vec<Node>) -> Graph {
-    let valves = HashMap::from_iter(valves.into_iter().map(|v| (v.name, v)));
-
-    let mut distances = HashMap::new();
-    for v in valves.values() {
-        for (n, d) in dijkstra(v.name, |n| valves[n].children.clone(), |_, _| 1) {
-            distances.insert((v.name, n), d);
+fn generate_graph(valves: &[Node]) -> (Graph<u64>, Tunnels) {
+    let mut graph = Graph::new();
+    for valve in valves {
+        graph.add_node(valve.name);
+        for child in &valve.children {
+            graph.add_edge(valve.name, child, 1);
         }
     }
-
-    Graph { valves, distances }
-}
-
-fn dijkstra<T, FN, FD>(start: T, get_neighbors: FN, get_distance: FD) -> HashMap<T, u64>
-where
-    T: Copy + Hash + Eq + Ord,
-    FN: Fn(&T) -> Vec<T>,
-    FD: Fn(&T, &T) -> u64,
-{
-    let mut distances = HashMap::new();
-    distances.insert(start, 0);
-
-    let mut queue = BinaryHeap::new();
-    queue.push((0, start));
-
-    while let Some((distance, point)) = queue.pop() {
-        if distances.contains_key(&point) && distance > distances[&point] {
-            continue;
-        }
-        for neighbor in get_neighbors(&point) {
-            let distance = distance + get_distance(&point, &neighbor);
-            if !distances.contains_key(&neighbor) || distances[&neighbor] > distance {
-                distances.insert(neighbor, distance);
-                queue.push((distance, neighbor));
-            }
-        }
+    let mut rates = vec![0; graph.len()];
+    for valve in valves {
+        rates[graph.id(valve.name).unwrap()] = valve.rate;
     }
-
-    distances
+    let distances = graph
+        .all_pairs_shortest_paths()
+        .into_iter()
+        .map(|row| row.into_iter().map(|d| d.unwrap_or(u64::MAX)).collect())
+        .collect();
+    (graph, Tunnels { rates, distances })
 }
 
-fn maximize_flow(graph: &Graph, current: &str, time: u64, closed: HashSet<&str>) -> u64 {
-    if time < 2 {
-        return 0;
-    }
-    let flow = graph.valves[current].rate * (time - 1);
-    let max = graph.valves[current]
-        .children
+fn maximize_flow(tunnels: &Tunnels, current: usize, time: u64, closed: &[usize]) -> u64 {
+    closed
         .iter()
-        .map(|n| {
-            let distance = graph.distances[&(current, *n)];
-            let ignore_current = if time > distance {
-                maximize_flow(graph, current, time - distance, closed.clone())
-            } else {
-                0
-            };
-            let use_current = if flow > 0 && time - 1 > distance {
-                let closed = closed.difference(&HashSet::from([current])).cloned().collect();
-                maximize_flow(graph, current, time - distance - 1, closed)
-            } else {
-                0
-            };
-            ignore_current.max(use_current)
+        .enumerate()
+        .filter_map(|(i, &next)| {
+            let distance = tunnels.distances[current][next];
+            if distance.saturating_add(1) >= time {
+                return None;
+            }
+            let time = time - distance - 1;
+            let mut closed = closed.to_vec();
+            closed.remove(i);
+            Some(tunnels.rates[next] * time + maximize_flow(tunnels, next, time, &closed))
         })
         .max()
-        .unwrap();
-    return max;
-}
-
-pub fn part1() {
-    let input = stdio_string();
-    let valves = parse_input(&input).unwrap().1;
-    let graph = generate_graph(valves);
-    let all_nodes = HashSet::from_iter(graph.valves.keys().cloned());
-    let max = maximize_flow(&graph, "AA", 30, all_nodes);
-    println!("{}", max);
+        .unwrap_or(0)
 }
 
-pub fn part2() {
-    todo!()
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let valves = parse_all(input, parse_input)?;
+    let (graph, tunnels) = generate_graph(&valves);
+    let useful_valves = (0..graph.len())
+        .filter(|&v| tunnels.rates[v] > 0)
+        .collect_vec();
+    let max = maximize_flow(&tunnels, graph.id("AA").unwrap(), 30, &useful_valves);
+    Ok(max.into())
 }
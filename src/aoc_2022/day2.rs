@@ -1,21 +1,27 @@
-use crate::std_iter;
+use crate::prelude::*;
 
-pub fn part1() {
-    let score: u64 = std_iter!(Lines).map(|line| {
-        let opponent = line.as_bytes()[0] - b'A';
-        let mine = line.as_bytes()[2] - b'X';
-        let outcome = (mine + 4 - opponent) % 3;        
-        (mine + 1 + outcome * 3) as u64
-    }).sum();
-    println!("{}", score);
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let score: u64 = input
+        .lines()
+        .map(|line| {
+            let opponent = line.as_bytes()[0] - b'A';
+            let mine = line.as_bytes()[2] - b'X';
+            let outcome = (mine + 4 - opponent) % 3;
+            (mine + 1 + outcome * 3) as u64
+        })
+        .sum();
+    Ok(score.into())
 }
 
-pub fn part2() {
-    let score: u64 = std_iter!(Lines).map(|line| {
-        let opponent = line.as_bytes()[0] - b'A';
-        let outcome = line.as_bytes()[2] - b'X';
-        let mine = (opponent + outcome + 2) % 3;
-        (mine + 1 + outcome * 3) as u64
-    }).sum();
-    println!("{}", score);
-}
\ No newline at end of file
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let score: u64 = input
+        .lines()
+        .map(|line| {
+            let opponent = line.as_bytes()[0] - b'A';
+            let outcome = line.as_bytes()[2] - b'X';
+            let mine = (opponent + outcome + 2) % 3;
+            (mine + 1 + outcome * 3) as u64
+        })
+        .sum();
+    Ok(score.into())
+}
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-use crate::std_iter;
+use crate::prelude::*;
 
 fn score(c: u8) -> u64 {
     if c.is_ascii_lowercase() {
@@ -12,21 +12,23 @@ fn score(c: u8) -> u64 {
     }
 }
 
-pub fn part1() {
-    let total: u64 = std_iter!(Lines)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let total: u64 = input
+        .lines()
         .map(|line| {
             let buffer = line.as_bytes();
-            let first: HashSet<u8> = (&buffer[..buffer.len() / 2]).iter().map(|&c| c).collect();
-            let second: HashSet<u8> = (&buffer[buffer.len() / 2..]).iter().map(|&c| c).collect();
-            first.intersection(&second).map(|&s| s).next().unwrap()
+            let first: HashSet<u8> = buffer[..buffer.len() / 2].iter().copied().collect();
+            let second: HashSet<u8> = buffer[buffer.len() / 2..].iter().copied().collect();
+            first.intersection(&second).copied().next().unwrap()
         })
         .map(score)
         .sum();
-    println!("{}", total);
+    Ok(total.into())
 }
 
-pub fn part2() {
-    let total: u64 = std_iter!(Lines)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let total: u64 = input
+        .lines()
         .chunks(3)
         .into_iter()
         .map(|lines| {
@@ -40,5 +42,5 @@ pub fn part2() {
         })
         .map(score)
         .sum();
-    println!("{}", total);
+    Ok(total.into())
 }
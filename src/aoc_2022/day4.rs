@@ -1,26 +1,27 @@
 use crate::prelude::*;
-use crate::std_iter;
 
 fn parse_range(i: &str) -> IResult<&str, (u64, u64)> {
     separated_pair(parse_u64, is_a("-"), parse_u64)(i)
 }
 
-fn parse_line(i: &str) -> IResult<&str, ((u64, u64), (u64, u64))> {
+type Range = (u64, u64);
+
+fn parse_line(i: &str) -> IResult<&str, (Range, Range)> {
     separated_pair(parse_range, is_a(","), parse_range)(i)
 }
 
-pub fn part1() {
-    let count = std_iter!(Lines)
-        .map(|line| parse_line(&line).expect("Can't parse").1)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let count = parse_lines(input, parse_line)?
+        .into_iter()
         .filter(|((l1, r1), (l2, r2))| (l1 >= l2 && r1 <= r2) || (l2 >= l1 && r2 <= r1))
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
 
-pub fn part2() {
-    let count = std_iter!(Lines)
-        .map(|line| parse_line(&line).expect("Can't parse").1)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let count = parse_lines(input, parse_line)?
+        .into_iter()
         .filter(|((l1, r1), (l2, r2))| !(r1 < l2 || l1 > r2))
         .count();
-    println!("{}", count);
+    Ok(count.into())
 }
@@ -1,6 +1,6 @@
 use nom::character::complete::anychar;
 
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
 fn parse_box(i: &str) -> IResult<&str, char> {
     alt((
@@ -14,55 +14,50 @@ fn parse_row(i: &str) -> IResult<&str, Vec<char>> {
 }
 
 fn parse_instruction(i: &str) -> IResult<&str, (u64, u64, u64)> {
-    let (i, count) = preceded(tag("move "), parse_u64)(i)?;
-    let (i, from) = preceded(tag(" from "), parse_u64)(i)?;
-    let (i, to) = preceded(tag(" to "), parse_u64)(i)?;
-
+    let (i, (count, from, to)) = template::<(u64, u64, u64)>("move {} from {} to {}")(i)?;
     Ok((i, (count, from - 1, to - 1)))
 }
 
-fn input() -> (Vec<std::collections::VecDeque<char>>, Vec<(u64, u64, u64)>) {
-    let mut grid = vec![];
-    let mut instructions = vec![];
-    for line in std_iter!(Lines) {
-        if let Ok((_, s)) = parse_row(&line) {
-            if grid.len() == 0 {
-                for _ in 0..s.len() {
-                    grid.push(VecDeque::new());
-                }
-            }
-            for (i, c) in s.into_iter().enumerate() {
-                if c != ' ' {
-                    grid[i].push_front(c);
-                }
+type Stacks = Vec<VecDeque<char>>;
+type Moves = Vec<(u64, u64, u64)>;
+
+/// The crate rows from the top down, followed by a line numbering the stacks.
+fn parse_stacks(i: &str) -> IResult<&str, Stacks> {
+    let (i, rows) = separated_list1(char('\n'), parse_row)(i)?;
+    let (i, _) = preceded(char('\n'), many1(preceded(space0, parse_u64)))(i)?;
+
+    let mut grid = vec![VecDeque::new(); rows[0].len()];
+    for row in rows {
+        for (i, c) in row.into_iter().enumerate() {
+            if c != ' ' {
+                grid[i].push_front(c);
             }
-        } else if let Ok((_, v)) = parse_instruction(&line) {
-            instructions.push(v);
         }
     }
-    return (grid, instructions);
+    Ok((i, grid))
 }
 
-fn print_top(grid: &Vec<VecDeque<char>>) {
-    for stack in grid {
-        print!("{}", stack.back().unwrap())
-    }
-    println!("");
+fn parse_input(input: &str) -> Result<(Stacks, Moves), ParseError> {
+    parse_sections(input, (parse_stacks, lines_of(parse_instruction)))
+}
+
+fn top_of_stacks(grid: &[VecDeque<char>]) -> String {
+    grid.iter().map(|stack| stack.back().unwrap()).collect()
 }
 
-pub fn part1() {
-    let (mut grid, instructions) = input();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let (mut grid, instructions) = parse_input(input)?;
     for (count, from, to) in instructions {
         for _ in 0..count {
             let c = grid[from as usize].pop_back().unwrap();
             grid[to as usize].push_back(c);
         }
     }
-    print_top(&grid);
+    Ok(top_of_stacks(&grid).into())
 }
 
-pub fn part2() {
-    let (mut grid, instructions) = input();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let (mut grid, instructions) = parse_input(input)?;
     for (count, from, to) in instructions {
         let mut temp = vec![];
         for _ in 0..count {
@@ -72,5 +67,5 @@ pub fn part2() {
         temp.reverse();
         grid[to as usize].extend(temp);
     }
-    print_top(&grid);
+    Ok(top_of_stacks(&grid).into())
 }
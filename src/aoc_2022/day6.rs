@@ -1,33 +1,33 @@
 use std::collections::HashSet;
 
-use crate::std_iter;
+use crate::prelude::*;
 
-pub fn part1() {
-    let i = std_iter!(Lines)
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let i = input
+        .lines()
         .next()
         .unwrap()
         .as_bytes()
         .windows(4)
         .enumerate()
-        .filter(|&(_, w)| HashSet::<u8>::from_iter(w.iter().copied()).len() == 4)
-        .next()
+        .find(|&(_, w)| HashSet::<u8>::from_iter(w.iter().copied()).len() == 4)
         .unwrap()
         .0;
 
-    println!("{}", i + 4);
+    Ok((i + 4).into())
 }
 
-pub fn part2() {
-    let i = std_iter!(Lines)
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let i = input
+        .lines()
         .next()
         .unwrap()
         .as_bytes()
         .windows(14)
         .enumerate()
-        .filter(|&(_, w)| HashSet::<u8>::from_iter(w.iter().copied()).len() == 14)
-        .next()
+        .find(|&(_, w)| HashSet::<u8>::from_iter(w.iter().copied()).len() == 14)
         .unwrap()
         .0;
 
-    println!("{}", i + 14);
+    Ok((i + 14).into())
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
+use crate::debug_log;
 use crate::prelude::*;
-use crate::std_iter;
 
 enum Line<'a> {
     Cd(&'a str),
@@ -10,27 +10,27 @@ enum Line<'a> {
     File(u64, &'a str),
 }
 
-fn parse_cd(i: &str) -> IResult<&str, Line> {
+fn parse_cd(i: &str) -> IResult<&str, Line<'_>> {
     let (i, r) = preceded(tag("$ cd "), take_while(|_| true))(i)?;
     Ok((i, Line::Cd(r)))
 }
 
-fn parse_ls(i: &str) -> IResult<&str, Line> {
+fn parse_ls(i: &str) -> IResult<&str, Line<'_>> {
     let (i, _) = tag("$ ls")(i)?;
     Ok((i, Line::Ls))
 }
 
-fn parse_dir(i: &str) -> IResult<&str, Line> {
+fn parse_dir(i: &str) -> IResult<&str, Line<'_>> {
     let (i, name) = preceded(tag("dir "), take_while(|_| true))(i)?;
     Ok((i, Line::Dir(name)))
 }
 
-fn parse_file(i: &str) -> IResult<&str, Line> {
+fn parse_file(i: &str) -> IResult<&str, Line<'_>> {
     let (i, (size, name)) = tuple((parse_u64, take_while(|_| true)))(i)?;
     Ok((i, Line::File(size, name)))
 }
 
-fn parse_line(i: &str) -> IResult<&str, Line> {
+fn parse_line(i: &str) -> IResult<&str, Line<'_>> {
     alt((parse_cd, parse_ls, parse_dir, parse_file))(i)
 }
 
@@ -46,19 +46,17 @@ fn count(
             .sum();
         sizes.insert(name.to_string(), size);
     }
-    return sizes[name];
+    sizes[name]
 }
 
-fn make_fs() -> HashMap<String, u64> {
+fn make_fs(input: &str) -> Result<HashMap<String, u64>, ParseError> {
     let mut sizes: HashMap<String, u64> = HashMap::new();
     let mut children: HashMap<String, Vec<String>> = HashMap::new();
     let mut current_path = vec!["/"];
 
-    let lines = std_iter!(Lines).collect_vec();
-
-    for line in lines.iter() {
+    for line in parse_lines(input, parse_line)? {
         let cwd = current_path.join("");
-        match parse_line(&line).unwrap().1 {
+        match line {
             Line::Cd("..") => {
                 current_path.pop();
                 current_path.pop();
@@ -74,11 +72,11 @@ fn make_fs() -> HashMap<String, u64> {
                 children.insert(cwd, vec![]);
             }
             Line::Dir(name) => {
-                let child_name = cwd.clone() + &name + "/";
+                let child_name = cwd.clone() + name + "/";
                 children.get_mut(&cwd).unwrap().push(child_name);
             }
             Line::File(size, name) => {
-                let child_name = cwd.clone() + &name;
+                let child_name = cwd.clone() + name;
                 children.get_mut(&cwd).unwrap().push(child_name.clone());
                 sizes.insert(child_name, size);
             }
@@ -86,23 +84,23 @@ fn make_fs() -> HashMap<String, u64> {
     }
 
     count("/", &mut sizes, &children);
-    sizes
+    Ok(sizes)
 }
 
-pub fn part1() {
-    let total: u64 = make_fs()
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let total: u64 = make_fs(input)?
         .iter()
         .filter(|&(name, count)| name.ends_with("/") && *count <= 100_000)
         .map(|v| v.1)
         .sum();
-    println!("{}", total);
+    Ok(total.into())
 }
 
-pub fn part2() {
-    let sizes = make_fs();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let sizes = make_fs(input)?;
     let spare = 70_000_000 - sizes["/"];
     let needed = 30_000_000 - spare;
-    eprintln!("Need {}", needed);
+    debug_log!("Need {}", needed);
 
     let to_delete = sizes
         .iter()
@@ -110,5 +108,5 @@ pub fn part2() {
         .map(|v| v.1)
         .min()
         .unwrap();
-    println!("{}", to_delete);
+    Ok((*to_delete).into())
 }
@@ -1,14 +1,6 @@
 use std::collections::HashSet;
 
-use itertools::Itertools;
-
-use crate::std_iter;
-
-fn input() -> Vec<Vec<u8>> {
-    std_iter!(Lines)
-        .map(|l| l.bytes().collect_vec())
-        .collect_vec()
-}
+use crate::prelude::*;
 
 fn count_visible(mut numbers: impl Iterator<Item = u8>) -> Vec<usize> {
     let mut max = numbers.next().unwrap();
@@ -24,29 +16,31 @@ fn count_visible(mut numbers: impl Iterator<Item = u8>) -> Vec<usize> {
     visible
 }
 
-pub fn part1() {
-    let grid = input();
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let grid = input.grid()?;
     let mut visible = HashSet::new();
 
-    for r in 0..grid.len() {
-        for c in count_visible(grid[r].iter().copied()) {
+    let (width, height) = (grid.width(), grid.height());
+
+    for (r, row) in grid.rows().enumerate() {
+        for c in count_visible(row.iter().copied()) {
             visible.insert((r, c));
         }
-        for c in count_visible(grid[r].iter().rev().copied()) {
-            visible.insert((r, grid[0].len() - 1 - c));
+        for c in count_visible(row.iter().rev().copied()) {
+            visible.insert((r, width - 1 - c));
         }
     }
 
-    for c in 0..grid[0].len() {
-        for r in count_visible((0..grid.len()).map(|r| grid[r][c])) {
+    for c in 0..width {
+        for r in count_visible(grid.col(c).copied()) {
             visible.insert((r, c));
         }
-        for r in count_visible((0..grid.len()).rev().map(|r| grid[r][c])) {
-            visible.insert((grid.len() - 1 - r, c));
+        for r in count_visible(grid.col(c).rev().copied()) {
+            visible.insert((height - 1 - r, c));
         }
     }
 
-    println!("{}", visible.len());
+    Ok(visible.len().into())
 }
 
 fn search_obstructed(trees: impl Iterator<Item = u8>, target: u8) -> usize {
@@ -57,21 +51,24 @@ fn search_obstructed(trees: impl Iterator<Item = u8>, target: u8) -> usize {
             break;
         }
     }
-    return i;
+    i
 }
 
-pub fn part2() {
-    let grid = input();
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let grid = input.grid()?;
+    let columns = grid.transpose();
     let mut max = 0;
-    for r in 1..grid.len() - 1 {
-        for c in 1..grid[0].len() - 1 {
-            let height = grid[r][c];
-            let n = search_obstructed((0..r).rev().map(|r| grid[r][c]), height);
-            let s = search_obstructed((r + 1..grid.len()).map(|r| grid[r][c]), height);
-            let w = search_obstructed((0..c).rev().map(|c| grid[r][c]), height);
-            let e = search_obstructed((c + 1..grid[0].len()).map(|c| grid[r][c]), height);
+    for r in 1..grid.height() - 1 {
+        for c in 1..grid.width() - 1 {
+            let height = grid[Coord::new(r, c)];
+            let row = grid.row(r);
+            let col = columns.row(c);
+            let n = search_obstructed(col[..r].iter().rev().copied(), height);
+            let s = search_obstructed(col[r + 1..].iter().copied(), height);
+            let w = search_obstructed(row[..c].iter().rev().copied(), height);
+            let e = search_obstructed(row[c + 1..].iter().copied(), height);
             max = max.max(n * s * w * e);
         }
     }
-    println!("{}", max);
+    Ok(max.into())
 }
@@ -1,59 +1,55 @@
 use nom::character::complete::one_of;
+use nom::combinator::map_res;
 
-use crate::{prelude::*, std_iter};
+use crate::prelude::*;
 
-fn parse(i: &str) -> IResult<&str, (char, u64)> {
-    separated_pair(one_of("LRUD"), tag(" "), parse_u64)(i)
+fn parse(i: &str) -> IResult<&str, (Direction, u64)> {
+    separated_pair(
+        map_res(one_of("LRUD"), Direction::try_from),
+        tag(" "),
+        parse_u64,
+    )(i)
 }
 
-fn step(initial: (i64, i64), direction: char) -> (i64, i64) {
-    match direction {
-        'L' => (initial.0 - 1, initial.1),
-        'R' => (initial.0 + 1, initial.1),
-        'U' => (initial.0, initial.1 + 1),
-        'D' => (initial.0, initial.1 - 1),
-        _ => unreachable!(),
+fn follow(head: Point2, tail: Point2) -> Point2 {
+    if head.chebyshev(tail) <= 1 {
+        tail
+    } else {
+        tail.step_towards(head)
     }
 }
 
-fn follow((hx, hy): (i64, i64), (tx, ty): (i64, i64)) -> (i64, i64) {
-    if (hx - tx).abs() <= 1 && (hy - ty).abs() <= 1 {
-        return (tx, ty);
-    }
-    let newx = if hx > tx { tx + 1 } else if hx < tx { tx - 1 } else { tx };
-    let newy = if hy > ty { ty + 1 } else if hy < ty { ty - 1 } else { ty };
-    return (newx, newy);
+/// The direction of every single step the head takes.
+fn head_steps(input: &str) -> Result<impl Iterator<Item = Direction> + '_, ParseError> {
+    Ok(parse_lines(input, parse)?
+        .into_iter()
+        .flat_map(|(direction, steps)| std::iter::repeat_n(direction, steps as usize)))
 }
 
-pub fn part1() {
-    let visited: HashSet<(i64, i64)> = std_iter!(Lines)
-        .map(|l| parse(&l).unwrap().1)
-        .map(|(direction, steps)| vec![direction; steps as usize].into_iter())
-        .flatten()
-        .scan((0, 0), |head, direction| {
-            *head = step(*head, direction);
+pub fn part1(input: &Input) -> Result<Answer, ParseError> {
+    let visited: HashSet<Point2> = head_steps(input)?
+        .scan(Point2::ORIGIN, |head, direction| {
+            *head += direction.offset();
             Some(*head)
         })
-        .scan((0, 0), |tail, head| {
+        .scan(Point2::ORIGIN, |tail, head| {
             *tail = follow(head, *tail);
             Some(*tail)
         })
         .collect();
 
-    println!("{}", visited.len());
+    Ok(visited.len().into())
 }
 
-pub fn part2() {
-    let visited: HashSet<(i64, i64)> = std_iter!(Lines)
-        .map(|l| parse(&l).unwrap().1)
-        .flat_map(|(direction, steps)| vec![direction; steps as usize].into_iter())
-        .scan(vec![(0, 0); 10], |points, direction| {
-            points[0] = step(points[0], direction);
+pub fn part2(input: &Input) -> Result<Answer, ParseError> {
+    let visited: HashSet<Point2> = head_steps(input)?
+        .scan([Point2::ORIGIN; 10], |knots, direction| {
+            knots[0] += direction.offset();
             for i in 1..10 {
-                points[i] = follow(points[i - 1], points[i]);
+                knots[i] = follow(knots[i - 1], knots[i]);
             }
-            Some(points[9])
+            Some(knots[9])
         })
         .collect();
-    println!("{}", visited.len());
+    Ok(visited.len().into())
 }
@@ -1,4 +1,11 @@
 mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+pub(crate) mod day15;
+mod day16;
 mod day2;
 mod day3;
 mod day4;
@@ -7,29 +14,24 @@ mod day6;
 mod day7;
 mod day8;
 mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
 
-pub const SOLUTIONS: &[[fn() -> (); 2]] = &[
-    [day1::part1, day1::part2],
-    [day2::part1, day2::part2],
-    [day3::part1, day3::part2],
-    [day4::part1, day4::part2],
-    [day5::part1, day5::part2],
-    [day6::part1, day6::part2],
-    [day7::part1, day7::part2],
-    [day8::part1, day8::part2],
-    [day9::part1, day9::part2],
-    [day10::part1, day10::part2],
-    [day11::part1, day11::part2],
-    [day12::part1, day12::part2],
-    [day13::part1, day13::part2],
-    [day14::part1, day14::part2],
-    [day15::part1, day15::part2],
-    [day16::part1, day16::part2],
+use crate::answer::Solution;
+
+pub const SOLUTIONS: &[[Option<Solution>; 2]] = &[
+    [Some(day1::part1), Some(day1::part2)],
+    [Some(day2::part1), Some(day2::part2)],
+    [Some(day3::part1), Some(day3::part2)],
+    [Some(day4::part1), Some(day4::part2)],
+    [Some(day5::part1), Some(day5::part2)],
+    [Some(day6::part1), Some(day6::part2)],
+    [Some(day7::part1), Some(day7::part2)],
+    [Some(day8::part1), Some(day8::part2)],
+    [Some(day9::part1), Some(day9::part2)],
+    [Some(day10::part1), Some(day10::part2)],
+    [Some(day11::part1), Some(day11::part2)],
+    [Some(day12::part1), Some(day12::part2)],
+    [Some(day13::part1), Some(day13::part2)],
+    [Some(day14::part1), Some(day14::part2)],
+    [Some(day15::part1), Some(day15::part2)],
+    [Some(day16::part1), None],
 ];
@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Solution;
+use crate::input::Input;
+
+/// Timing statistics over repeated runs, in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+    pub min_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty());
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        } else {
+            nanos[n / 2]
+        };
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            samples: n,
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: variance.sqrt(),
+            min_ns: nanos[0],
+        }
+    }
+}
+
+/// How long to keep sampling a solution.
+pub struct BenchOptions {
+    pub max_iterations: usize,
+    pub max_time: Duration,
+}
+
+/// Runs a solution repeatedly, after one warm-up run, until either limit in `options` is hit.
+/// The input should already be known to parse: failed runs are timed like any other.
+pub fn measure(solution: Solution, input: &str, options: &BenchOptions) -> Stats {
+    let input = Input::from(input);
+    let _ = solution(&input);
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < options.max_iterations.max(1)
+        && (samples.is_empty() || start.elapsed() < options.max_time)
+    {
+        let tik = Instant::now();
+        let _ = std::hint::black_box(solution(std::hint::black_box(&input)));
+        samples.push(tik.elapsed());
+    }
+    Stats::from_samples(&samples)
+}
+
+/// Previously recorded timings, keyed by `"<year>-<day>-<part>"`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, Stats>);
+
+impl Baseline {
+    pub fn key(year: u32, day: usize, part: usize) -> String {
+        format!("{}-{}-{}", year, day, part)
+    }
+
+    /// Loads a baseline file, or an empty baseline if it does not exist yet.
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        if !path.exists() {
+            return Ok(Baseline::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string_pretty(self).unwrap();
+        std::fs::write(path, content + "\n")
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, year: u32, day: usize, part: usize) -> Option<&Stats> {
+        self.0.get(&Baseline::key(year, day, part))
+    }
+
+    pub fn insert(&mut self, year: u32, day: usize, part: usize, stats: Stats) {
+        self.0.insert(Baseline::key(year, day, part), stats);
+    }
+}
+
+/// Relative change of the median against a baseline, e.g. `0.1` for 10% slower. A zero
+/// baseline counts as unchanged by a zero median and infinitely slower otherwise.
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    if baseline.median_ns == 0.0 {
+        return if current.median_ns == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    current.median_ns / baseline.median_ns - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(nanos: &[u64]) -> Stats {
+        let samples: Vec<Duration> = nanos.iter().map(|&n| Duration::from_nanos(n)).collect();
+        Stats::from_samples(&samples)
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let odd = stats(&[30, 10, 20]);
+        assert_eq!((odd.samples, odd.median_ns, odd.min_ns), (3, 20.0, 10.0));
+        assert_eq!(odd.mean_ns, 20.0);
+
+        let even = stats(&[40, 10, 30, 20]);
+        assert_eq!((even.median_ns, even.mean_ns), (25.0, 25.0));
+        assert_eq!(even.stddev_ns, 125f64.sqrt());
+
+        let single = stats(&[7]);
+        assert_eq!((single.median_ns, single.stddev_ns), (7.0, 0.0));
+    }
+
+    #[test]
+    fn compares_medians() {
+        assert!((change(&stats(&[100]), &stats(&[110])) - 0.1).abs() < 1e-9);
+        assert_eq!(change(&stats(&[100]), &stats(&[50])), -0.5);
+        assert_eq!(change(&stats(&[0]), &stats(&[0])), 0.0);
+        assert_eq!(change(&stats(&[0]), &stats(&[5])), f64::INFINITY);
+    }
+
+    #[test]
+    fn measures_up_to_the_iteration_limit() {
+        let options = BenchOptions {
+            max_iterations: 5,
+            max_time: Duration::from_secs(60),
+        };
+        let stats = measure(|input| Ok(input.len().into()), "abc", &options);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(Baseline::key(2022, 1, 2), "2022-1-2");
+    }
+}
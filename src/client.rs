@@ -0,0 +1,166 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::inputs::{InputError, InputStore};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_CONFIG_FILE: &str = ".env";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Time to wait between two requests to the server.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+const USER_AGENT: &str = concat!("github.com/PowerSnail/aoc v", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession(String),
+    Status(u16, String),
+    Transport(String),
+    Input(InputError),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession(config) => write!(
+                f,
+                "No session cookie: set {} in the environment or in {}",
+                SESSION_VAR, config
+            ),
+            ClientError::Status(code, body) => {
+                write!(f, "Server responded with {}: {}", code, body.trim())
+            }
+            ClientError::Transport(message) => write!(f, "Request failed: {}", message),
+            ClientError::Input(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(code, response) => {
+                ClientError::Status(code, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl From<InputError> for ClientError {
+    fn from(e: InputError) -> Self {
+        ClientError::Input(e)
+    }
+}
+
+/// Finds the session cookie in `$AOC_SESSION`, or in an `AOC_SESSION=...` line of `config`.
+pub fn find_session(config: &Path) -> Result<String, ClientError> {
+    if let Some(session) = std::env::var(SESSION_VAR).ok().filter(|s| !s.is_empty()) {
+        return Ok(session);
+    }
+    std::fs::read_to_string(config)
+        .ok()
+        .and_then(|content| {
+            content.lines().find_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                (key.trim() == SESSION_VAR).then(|| value.trim().trim_matches('"').to_string())
+            })
+        })
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| ClientError::NoSession(config.display().to_string()))
+}
+
+/// A polite client for the puzzle server, waiting `interval` between requests.
+pub struct Client {
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            interval: DEFAULT_INTERVAL,
+            last_request: None,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Client {
+        self.interval = interval;
+        self
+    }
+
+    fn wait_turn(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&mut self, year: u32, day: usize) -> Result<String, ClientError> {
+        self.wait_turn();
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call()?;
+        response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))
+    }
+
+    /// Posts an answer and returns the page the server responds with.
+    pub fn answer(
+        &mut self,
+        year: u32,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        self.wait_turn();
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads an input into the store, unless it is already there, and records it as the
+/// question of both parts so their answers can be saved.
+pub fn fetch_input(
+    client: &mut Client,
+    store: &InputStore,
+    year: u32,
+    day: usize,
+) -> Result<Fetched, ClientError> {
+    if store.get(year, day, 1)?.is_some() {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.input(year, day)?;
+    store.save(year, day, &input)?;
+    store.add_questions(year, day, &input)?;
+    Ok(Fetched::Downloaded)
+}
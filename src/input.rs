@@ -0,0 +1,134 @@
+//! The puzzle input handed to every solution.
+
+use std::borrow::Cow;
+use std::io::Read;
+use std::ops::Deref;
+use std::path::Path;
+
+use crate::parsers::ParseError;
+use crate::prelude::Grid;
+
+/// A puzzle input, read from a file or stdin or wrapped around a string (as in tests).
+///
+/// It dereferences to the whole text, so it can go straight to the parsers. The
+/// accessors keep the text as it is, including any trailing newline; the `trimmed`
+/// ones drop surrounding whitespace first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Input<'a> {
+        Input {
+            text: Cow::Borrowed(text),
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<Input<'static>> {
+        std::fs::read_to_string(path).map(Input::from)
+    }
+
+    pub fn from_stdin() -> std::io::Result<Input<'static>> {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        Ok(Input::from(text))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    pub fn bytes(&self) -> std::str::Bytes<'_> {
+        self.text.bytes()
+    }
+
+    /// The first line split on `separator`.
+    pub fn split_by(&self, separator: char) -> std::str::Split<'_, char> {
+        self.text.lines().next().unwrap_or("").split(separator)
+    }
+
+    pub fn trimmed(&self) -> &str {
+        self.text.trim()
+    }
+
+    /// Every line with trailing whitespace removed, leaving out blank lines at the
+    /// start and end.
+    pub fn trimmed_lines(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .trim_matches(['\n', '\r'])
+            .lines()
+            .map(str::trim_end)
+    }
+
+    pub fn trimmed_bytes(&self) -> std::str::Bytes<'_> {
+        self.trimmed().bytes()
+    }
+
+    /// The bytes of each non-empty line as a grid row.
+    pub fn grid(&self) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(&self.text, |b| b)
+    }
+
+    /// Like [`Input::grid`], converting each byte with `cell`.
+    pub fn grid_of<T>(&self, cell: impl FnMut(u8) -> T) -> Result<Grid<T>, ParseError> {
+        Grid::parse(&self.text, cell)
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(text: &'a str) -> Input<'a> {
+        Input::new(text)
+    }
+}
+
+impl From<String> for Input<'static> {
+    fn from(text: String) -> Input<'static> {
+        Input {
+            text: Cow::Owned(text),
+        }
+    }
+}
+
+impl Deref for Input<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_text() {
+        let input = Input::from("1,2,3\nabc  \n\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1,2,3", "abc  ", ""]);
+        assert_eq!(input.trimmed_lines().collect::<Vec<_>>(), ["1,2,3", "abc"]);
+        assert_eq!(input.split_by(',').collect::<Vec<_>>(), ["1", "2", "3"]);
+        assert_eq!(input.bytes().last(), Some(b'\n'));
+
+        let input = Input::from(String::from("3113322113\n"));
+        assert_eq!(input.trimmed_bytes().count(), 10);
+        assert_eq!(input.trimmed(), "3113322113");
+        assert_eq!(&input[..2], "31");
+
+        let input = Input::from("#.\n.#\n");
+        assert_eq!(input.grid().unwrap().row(0), b"#.");
+        assert_eq!(
+            input
+                .grid_of(|b| b == b'#')
+                .unwrap()
+                .values()
+                .filter(|&&on| on)
+                .count(),
+            2
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::qa::QaDb;
+
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(PathBuf, std::io::Error),
+    Write(PathBuf, std::io::Error),
+    Db(rusqlite::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(path, e) => write!(f, "Failed to read {}: {}", path.display(), e),
+            InputError::Write(path, e) => write!(f, "Failed to write {}: {}", path.display(), e),
+            InputError::Db(e) => write!(f, "Failed to query the input database: {}", e),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for InputError {
+    fn from(e: rusqlite::Error) -> Self {
+        InputError::Db(e)
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|e| InputError::Io(path.to_path_buf(), e))
+}
+
+/// Puzzle inputs kept on disk, as `<dir>/<year>/<day>.txt` files or in the `QA` table of
+/// `<dir>/qa.sqlite` written by `run.py download_input`.
+pub struct InputStore {
+    dir: PathBuf,
+    db: Option<QaDb>,
+}
+
+impl InputStore {
+    pub fn open(dir: impl AsRef<Path>) -> Result<InputStore, InputError> {
+        let dir = dir.as_ref().to_path_buf();
+        let db_path = dir.join("qa.sqlite");
+        let db = if db_path.exists() {
+            Some(QaDb::open(db_path)?)
+        } else {
+            None
+        };
+        Ok(InputStore { dir, db })
+    }
+
+    pub fn path(&self, year: u32, day: usize) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("{}.txt", day))
+    }
+
+    /// Saves an input as a text file, where `get` looks first.
+    pub fn save(&self, year: u32, day: usize, input: &str) -> Result<(), InputError> {
+        let path = self.path(year, day);
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir).map_err(|e| InputError::Write(dir.to_path_buf(), e))?;
+        std::fs::write(&path, input).map_err(|e| InputError::Write(path, e))
+    }
+
+    /// Stores an input as the question of both parts in `<dir>/qa.sqlite`, creating it if
+    /// needed, so that answers can be saved against it. Parts that already have a question
+    /// are left alone.
+    pub fn add_questions(&self, year: u32, day: usize, input: &str) -> Result<(), InputError> {
+        let db = QaDb::create(self.dir.join("qa.sqlite"))?;
+        for part in 1..=2 {
+            if db.question(year, day, part)?.is_none() {
+                db.add(year, day, part, input, None)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the input for a puzzle part, preferring the text file over the database.
+    pub fn get(&self, year: u32, day: usize, part: usize) -> Result<Option<String>, InputError> {
+        let path = self.path(year, day);
+        if path.exists() {
+            return read_file(&path).map(Some);
+        }
+        match &self.db {
+            Some(db) => Ok(db.question(year, day, part)?),
+            None => Ok(None),
+        }
+    }
+}
@@ -1,7 +1,19 @@
+// Lets `#[derive(AocParse)]` refer to `::aoc` from inside this crate too.
+extern crate self as aoc;
+
+pub mod answer;
 pub mod aoc_2015;
 pub mod aoc_2021;
 pub mod aoc_2022;
+pub mod bench;
+pub mod client;
+pub mod input;
+pub mod inputs;
 pub mod parsers;
+pub mod qa;
+pub mod registry;
+pub mod runner;
+pub mod submit;
 
 #[macro_use]
 pub mod prelude;
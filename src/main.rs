@@ -1,21 +1,651 @@
-use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc::answer::Answer;
+use aoc::bench::{self, Baseline, BenchOptions};
+use aoc::client::{self, Client, Fetched, DEFAULT_BASE_URL, DEFAULT_CONFIG_FILE};
+use aoc::input::Input;
+use aoc::inputs::{InputStore, DEFAULT_INPUT_DIR};
+use aoc::prelude::{set_verbose, stdio_string};
+use aoc::qa::{self, QaDb};
+use aoc::registry::{self, Status};
+use aoc::runner::{self, Record};
+use aoc::submit::{self, Verdict};
+use clap::{ArgEnum, Parser, Subcommand};
 
 #[derive(Parser)]
 #[clap(version = "1.0")]
 #[derive(Debug)]
 struct Opts {
+    /// Directory holding <year>/<day>.txt inputs and the qa.sqlite input database
+    #[clap(long, global = true, default_value = DEFAULT_INPUT_DIR)]
+    inputs: PathBuf,
+    /// How to print answers: a table for humans, or one JSON record per line
+    #[clap(long, global = true, arg_enum, default_value = "text")]
+    format: Format,
+    /// Server to download inputs from
+    #[clap(long, global = true, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+    /// File with an AOC_SESSION=<cookie> line, used when the variable is not set
+    #[clap(long, global = true, default_value = DEFAULT_CONFIG_FILE)]
+    config: PathBuf,
+    /// Print the debug tracing of solutions to stderr
+    #[clap(short, long, global = true)]
+    verbose: bool,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run one puzzle part on the input from a file, stdin or the input store
+    Run {
+        year: u32,
+        day: usize,
+        part: usize,
+        /// Read the input from this file, or from stdin if it is "-"
+        #[clap(long)]
+        input: Option<PathBuf>,
+    },
+    /// Run every registered solution on the stored inputs and time it
+    RunAll {
+        #[clap(long)]
+        year: Option<u32>,
+        #[clap(long)]
+        day: Option<usize>,
+    },
+    /// Show which puzzles have solutions
+    List,
+    /// Time a solution over many runs and compare it against the saved baseline
+    Bench {
+        year: u32,
+        day: usize,
+        /// Benchmark only this part instead of both
+        part: Option<usize>,
+        /// Read the input from this file, or from stdin if it is "-"
+        #[clap(long)]
+        input: Option<PathBuf>,
+        /// Stop after this many runs
+        #[clap(long, default_value = "100")]
+        iterations: usize,
+        /// Stop after this many seconds, even if fewer runs were made
+        #[clap(long, default_value = "10")]
+        max_seconds: f64,
+        #[clap(long, default_value = "bench/baseline.json")]
+        baseline: PathBuf,
+        /// Slowdown of the median, in percent, that counts as a regression
+        #[clap(long, default_value = "10")]
+        threshold: f64,
+        /// Record these timings as the new baseline
+        #[clap(long)]
+        save: bool,
+    },
+    /// Download puzzle inputs into the input store, skipping those already there
+    Fetch {
+        year: u32,
+        /// Download only this day instead of the whole year
+        day: Option<usize>,
+    },
+    /// Submit the answer to a puzzle part and record the verdict in <inputs>/qa.sqlite
+    Submit {
+        year: u32,
+        day: usize,
+        part: usize,
+        /// Read the input from this file, or from stdin if it is "-"
+        #[clap(long)]
+        input: Option<PathBuf>,
+        /// Submit this answer instead of running the solution, e.g. for screens read by eye
+        #[clap(long)]
+        answer: Option<String>,
+    },
+    /// Manage the question and answer database
+    Db {
+        /// Database to use, tests/qa.sqlite by default, or <inputs>/qa.sqlite for save-answer
+        #[clap(long)]
+        db: Option<PathBuf>,
+        #[clap(subcommand)]
+        command: DbCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DbCommand {
+    /// Add a question with its expected answer
+    Add {
+        year: u32,
+        day: usize,
+        part: usize,
+        /// The puzzle input, or "-" to read it from stdin
+        question: String,
+        answer: String,
+    },
+    /// Show the stored questions and answers
+    List {
+        #[clap(long)]
+        year: Option<u32>,
+        #[clap(long)]
+        day: Option<usize>,
+        #[clap(long)]
+        part: Option<usize>,
+    },
+    /// Check that the solutions still give the stored answers
+    Verify {
+        #[clap(long)]
+        year: Option<u32>,
+        #[clap(long)]
+        day: Option<usize>,
+        #[clap(long)]
+        part: Option<usize>,
+    },
+    /// Run a solution on its stored questions and record the answers
+    SaveAnswer {
+        year: u32,
+        day: usize,
+        part: usize,
+        /// Overwrite answers that differ from the new ones
+        #[clap(long)]
+        force: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let opts: Opts = Opts::parse();
+    set_verbose(opts.verbose);
+
+    let result = match opts.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(year, day, part, input.as_deref(), &opts.inputs, opts.format),
+        Command::RunAll { year, day } => run_all(year, day, &opts.inputs, opts.format),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Bench {
+            year,
+            day,
+            part,
+            input,
+            iterations,
+            max_seconds,
+            baseline,
+            threshold,
+            save,
+        } => {
+            let options = BenchOptions {
+                max_iterations: iterations,
+                max_time: Duration::from_secs_f64(max_seconds),
+            };
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            let input = input.as_deref();
+            bench(
+                year,
+                day,
+                &parts,
+                input,
+                &opts.inputs,
+                &options,
+                &baseline,
+                threshold,
+                save,
+            )
+        }
+        Command::Fetch { year, day } => fetch(year, day, &opts),
+        Command::Submit {
+            year,
+            day,
+            part,
+            ref input,
+            ref answer,
+        } => submit(year, day, part, input.as_deref(), answer.clone(), &opts),
+        Command::Db { db, command } => {
+            // Only the default database holds puzzle examples rather than real inputs.
+            let examples = db.is_none();
+            let path = db.unwrap_or_else(|| match command {
+                DbCommand::SaveAnswer { .. } => opts.inputs.join("qa.sqlite"),
+                _ => PathBuf::from("tests/qa.sqlite"),
+            });
+            // Only adding a case may create the database; the other commands need it to exist.
+            let db = match command {
+                DbCommand::Add { .. } => QaDb::create(&path),
+                _ => QaDb::open(&path),
+            };
+            db.map_err(|e| format!("Failed to open {}: {}", path.display(), e))
+                .and_then(|db| run_db(&db, command, examples))
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
     year: u32,
     day: usize,
     part: usize,
+    input: Option<&Path>,
+    inputs: &Path,
+    format: Format,
+) -> Result<(), String> {
+    let solution = registry::lookup(year, day, part)?;
+    let input = read_input(year, day, part, input, inputs)?;
+    let record = Record::from_run(year, day, part, runner::run(year, day, solution, &input));
+    match format {
+        Format::Text => {
+            if let Some(answer) = &record.answer {
+                println!("{}", answer);
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+    }
+    match record.error {
+        Some(message) => Err(format!(
+            "{} day {} part {} failed: {}",
+            year, day, part, message
+        )),
+        None => Ok(()),
+    }
 }
 
-fn main() {
-    let opts: Opts = Opts::parse();
+/// Reads the input from `--input` if given (stdin for "-"), otherwise from the input store.
+fn read_input(
+    year: u32,
+    day: usize,
+    part: usize,
+    input: Option<&Path>,
+    inputs: &Path,
+) -> Result<Input<'static>, String> {
+    match input {
+        Some(path) if path == Path::new("-") => {
+            return Input::from_stdin().map_err(|e| format!("Failed to read stdin: {}", e))
+        }
+        Some(path) => {
+            return Input::from_file(path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+        }
+        None => {}
+    }
+    let store = InputStore::open(inputs).map_err(|e| e.to_string())?;
+    store
+        .get(year, day, part)
+        .map_err(|e| e.to_string())?
+        .map(Input::from)
+        .ok_or_else(|| {
+            format!(
+                "No input for {} day {}: pass --input (\"-\" for stdin) or save it as {}",
+                year,
+                day,
+                store.path(year, day).display()
+            )
+        })
+}
+
+fn run_all(
+    year: Option<u32>,
+    day: Option<usize>,
+    inputs: &Path,
+    format: Format,
+) -> Result<(), String> {
+    let store = InputStore::open(inputs).map_err(|e| e.to_string())?;
+    // Failures are reported in the table; the default hook would interleave backtraces.
+    std::panic::set_hook(Box::new(|_| {}));
+
+    if format == Format::Text {
+        println!(
+            "{:<6}{:<5}{:<6}{:<32}{:>12}",
+            "Year", "Day", "Part", "Answer", "Time (ms)"
+        );
+    }
+    let mut year_total = Duration::ZERO;
+    let mut current_year = None;
+    for (y, d, p) in registry::puzzles() {
+        if year.is_some_and(|year| year != y) || day.is_some_and(|day| day != d) {
+            continue;
+        }
+        if let Some(previous) = current_year.filter(|&previous| previous != y) {
+            if format == Format::Text {
+                print_total(previous, year_total);
+            }
+            year_total = Duration::ZERO;
+        }
+        current_year = Some(y);
+
+        let record = match registry::status(y, d, p) {
+            Status::Implemented(solution) => match store.get(y, d, p).map_err(|e| e.to_string())? {
+                Some(input) => Record::from_run(y, d, p, runner::run(y, d, solution, &input)),
+                None => Record::skipped(y, d, p, "no input"),
+            },
+            status => Record::skipped(y, d, p, status.name()),
+        };
+        year_total += Duration::from_nanos(record.elapsed_ns.unwrap_or(0) as u64);
+        match format {
+            Format::Text => print_row(&record),
+            Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+        }
+    }
+    if let Some(y) = current_year.filter(|_| format == Format::Text) {
+        print_total(y, year_total);
+    }
+    Ok(())
+}
+
+fn print_row(record: &Record) {
+    let answer = match (&record.answer, &record.error) {
+        (Some(answer), _) => summarize(answer),
+        (None, Some(message)) => format!("(failed: {})", summarize(message)),
+        (None, None) => format!("({})", record.status),
+    };
+    let time = match record.elapsed_ns {
+        Some(ns) => format!("{:.3}", ns as f64 / 1e6),
+        None => String::from("-"),
+    };
+    println!(
+        "{:<6}{:<5}{:<6}{:<32}{:>12}",
+        record.year, record.day, record.part, answer, time
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bench(
+    year: u32,
+    day: usize,
+    parts: &[usize],
+    input: Option<&Path>,
+    inputs: &Path,
+    options: &BenchOptions,
+    baseline_path: &Path,
+    threshold: f64,
+    save: bool,
+) -> Result<(), String> {
+    let mut baseline = Baseline::load(baseline_path)?;
+    let mut regressions = 0;
+    // Both parts of a day share one input, so it is read once for all of them.
+    let input = read_input(year, day, parts[0], input, inputs)?;
+
+    println!(
+        "{:<12}{:>8}{:>12}{:>12}{:>12}{:>12}  Change",
+        "Puzzle", "Runs", "Mean (ms)", "Median (ms)", "Stddev (ms)", "Min (ms)"
+    );
+    for &part in parts {
+        let solution = registry::lookup(year, day, part)?;
+        if let Err(message) = runner::run(year, day, solution, &input).answer {
+            return Err(format!(
+                "{} day {} part {} failed: {}",
+                year, day, part, message
+            ));
+        }
+
+        let stats = bench::measure(solution, &input, options);
+        let change = match baseline.get(year, day, part) {
+            Some(previous) => {
+                let change = bench::change(previous, &stats);
+                if change * 100.0 > threshold {
+                    regressions += 1;
+                    format!("{:+.1}% REGRESSED", change * 100.0)
+                } else {
+                    format!("{:+.1}%", change * 100.0)
+                }
+            }
+            None => String::from("(no baseline)"),
+        };
+        println!(
+            "{:<12}{:>8}{:>12.3}{:>12.3}{:>12.3}{:>12.3}  {}",
+            Baseline::key(year, day, part),
+            stats.samples,
+            stats.mean_ns / 1e6,
+            stats.median_ns / 1e6,
+            stats.stddev_ns / 1e6,
+            stats.min_ns / 1e6,
+            change
+        );
+        baseline.insert(year, day, part, stats);
+    }
+
+    if save {
+        baseline.save(baseline_path)?;
+    }
+    if regressions > 0 {
+        return Err(format!(
+            "{} benchmark(s) regressed more than {}%",
+            regressions, threshold
+        ));
+    }
+    Ok(())
+}
+
+fn fetch(year: u32, day: Option<usize>, opts: &Opts) -> Result<(), String> {
+    let days = match day {
+        Some(day) => {
+            registry::check_day(day)?;
+            vec![day]
+        }
+        None => (1..=registry::DAYS).collect(),
+    };
+    let store = InputStore::open(&opts.inputs).map_err(|e| e.to_string())?;
+    let session = client::find_session(&opts.config).map_err(|e| e.to_string())?;
+    let mut client = Client::new(&opts.base_url, &session);
+    for day in days {
+        match client::fetch_input(&mut client, &store, year, day) {
+            Ok(Fetched::Cached) => println!("{} day {}: already downloaded", year, day),
+            Ok(Fetched::Downloaded) => println!(
+                "{} day {}: saved to {}",
+                year,
+                day,
+                store.path(year, day).display()
+            ),
+            Err(e) => return Err(format!("{} day {}: {}", year, day, e)),
+        }
+    }
+    Ok(())
+}
+
+fn submit(
+    year: u32,
+    day: usize,
+    part: usize,
+    input: Option<&Path>,
+    answer: Option<String>,
+    opts: &Opts,
+) -> Result<(), String> {
+    let question = read_input(year, day, part, input, &opts.inputs)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            // Only needed when there is no answer yet, so unsolved days can still be submitted.
+            let solution = registry::lookup(year, day, part)?;
+            match runner::run(year, day, solution, &question).answer? {
+                Answer::Screen(_) => {
+                    return Err(String::from(
+                        "The answer is a screen: read it and pass it with --answer",
+                    ))
+                }
+                answer => qa::format_answer(&answer),
+            }
+        }
+    };
+
+    let path = opts.inputs.join("qa.sqlite");
+    let db = QaDb::create(&path)
+        .and_then(|db| db.create_attempts().map(|()| db))
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let session = client::find_session(&opts.config).map_err(|e| e.to_string())?;
+    let mut client = Client::new(&opts.base_url, &session);
+    println!("Submitting {}", answer);
+    let outcome = submit::submit(&mut client, &db, year, day, part, &question, &answer)
+        .map_err(|e| e.to_string())?;
+    println!("{}", outcome.message);
+    match outcome.verdict {
+        Verdict::Correct if !outcome.saved => Err(format!(
+            "The answer was accepted but not saved: {} has no question for {} day {} part {} \
+             matching this input",
+            path.display(),
+            year,
+            day,
+            part
+        )),
+        Verdict::Correct => Ok(()),
+        verdict => Err(format!("The answer was not accepted ({})", verdict.name())),
+    }
+}
+
+fn run_db(db: &QaDb, command: DbCommand, examples: bool) -> Result<(), String> {
+    match command {
+        DbCommand::Add {
+            year,
+            day,
+            part,
+            question,
+            answer,
+        } => {
+            registry::lookup(year, day, part)?;
+            let question = if question == "-" {
+                stdio_string()
+            } else {
+                question
+            };
+            db.add(year, day, part, &question, Some(&answer))
+                .map_err(|e| e.to_string())
+        }
+        DbCommand::List { year, day, part } => {
+            let cases = db.cases(year, day, part).map_err(|e| e.to_string())?;
+            println!(
+                "{:<6}{:<5}{:<6}{:<32}Question",
+                "Year", "Day", "Part", "Answer"
+            );
+            for case in cases {
+                let answer = case.answer.as_deref().map_or(String::from("-"), summarize);
+                println!(
+                    "{:<6}{:<5}{:<6}{:<32}{}",
+                    case.year,
+                    case.day,
+                    case.part,
+                    answer,
+                    summarize(&case.question)
+                );
+            }
+            Ok(())
+        }
+        DbCommand::Verify { year, day, part } => {
+            let cases = db.cases(year, day, part).map_err(|e| e.to_string())?;
+            std::panic::set_hook(Box::new(|_| {}));
+            let mut failures = 0;
+            for case in cases {
+                let expected = match &case.answer {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+                let name = format!("{} day {} part {}", case.year, case.day, case.part);
+                let solution = match registry::status(case.year, case.day, case.part) {
+                    Status::Implemented(_) if examples => {
+                        registry::lookup_example(case.year, case.day, case.part)?
+                    }
+                    Status::Implemented(solution) => solution,
+                    status => {
+                        println!("{:<24}skipped ({})", name, status.name());
+                        continue;
+                    }
+                };
+                match runner::run(case.year, case.day, solution, &case.question).answer {
+                    Ok(answer) if qa::format_answer(&answer) == *expected => {
+                        println!("{:<24}ok", name);
+                    }
+                    Ok(answer) => {
+                        failures += 1;
+                        println!(
+                            "{:<24}FAILED: expected {:?}, got {:?}",
+                            name,
+                            expected,
+                            qa::format_answer(&answer)
+                        );
+                    }
+                    Err(message) => {
+                        failures += 1;
+                        println!("{:<24}FAILED: {}", name, message);
+                    }
+                }
+            }
+            if failures > 0 {
+                return Err(format!("{} case(s) failed", failures));
+            }
+            Ok(())
+        }
+        DbCommand::SaveAnswer {
+            year,
+            day,
+            part,
+            force,
+        } => {
+            let solution = registry::lookup(year, day, part)?;
+            let cases = db
+                .cases(Some(year), Some(day), Some(part))
+                .map_err(|e| e.to_string())?;
+            if cases.is_empty() {
+                return Err(format!(
+                    "No questions stored for {} day {} part {}",
+                    year, day, part
+                ));
+            }
+            for case in cases {
+                let run = runner::run(case.year, case.day, solution, &case.question);
+                let answer = qa::format_answer(&run.answer?);
+                println!("{}", answer);
+                println!("Took {:.2}ms", run.elapsed.as_secs_f64() * 1000.0);
+                if let Some(previous) = case.answer.as_ref().filter(|&previous| *previous != answer)
+                {
+                    if !force {
+                        return Err(format!(
+                            "Answer changed: expected {:?} but got {:?} (pass --force to overwrite)",
+                            previous, answer
+                        ));
+                    }
+                }
+                db.save_answer(&case, &answer).map_err(|e| e.to_string())?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn list() {
+    println!("{:<6}{:<5}{:<16}Part 2", "Year", "Day", "Part 1");
+    for year in registry::YEARS {
+        for day in 1..=registry::DAYS {
+            let [part1, part2] = [1, 2].map(|part| registry::status(year, day, part).name());
+            println!("{:<6}{:<5}{:<16}{}", year, day, part1, part2);
+        }
+    }
+}
+
+fn print_total(year: u32, total: Duration) {
+    println!(
+        "{:<6}{:<43}{:>12.3}",
+        year,
+        "Total",
+        total.as_secs_f64() * 1000.0
+    );
+}
 
-    match (opts.year, opts.day, opts.part) {
-        (2015, day, part) => aoc::aoc_2015::SOLUTIONS[day - 1][part - 1](),
-        (2021, day, part) => aoc::aoc_2021::SOLUTIONS[day - 1][part - 1](),
-        (2022, day, part) => aoc::aoc_2022::SOLUTIONS[day - 1][part - 1](),
-        _ => eprintln!("Error: Unknown options {:?}", opts),
+/// Shortens an answer to one line that fits in the answer column.
+fn summarize(s: &str) -> String {
+    let first_line = s.lines().next().unwrap_or("");
+    let mut summary: String = first_line.chars().take(28).collect();
+    if summary.len() < s.len() {
+        summary.push_str("...");
     }
+    summary
 }
@@ -1,12 +1,14 @@
-use std::str::FromStr;
+use std::fmt::Display;
 
-use nom::bytes::complete::{take, take_until};
-use nom::character::complete::char;
-use nom::error::{ErrorKind, ParseError};
-use nom::{branch::alt, bytes::complete::take_while, combinator::fail, IResult};
+use nom::bytes::complete::{tag, take, take_till, take_until};
+use nom::character::complete::anychar;
+use nom::error::{ErrorKind, ParseError as NomParseError};
+use nom::multi::separated_list1;
+pub use nom::IResult;
 use nom::{FindSubstring, InputIter, InputLength, InputTake};
+use num_traits::PrimInt;
 
-pub fn take_after<T, Input, Error: ParseError<Input>>(
+pub fn take_after<T, Input, Error: NomParseError<Input>>(
     tag: T,
 ) -> impl Fn(Input) -> IResult<Input, Input, Error>
 where
@@ -22,47 +24,664 @@ where
 }
 
 pub fn is_digit(c: char) -> bool {
-    c.is_digit(10)
+    c.is_ascii_digit()
 }
 
-pub fn parse_u64(line: &str) -> IResult<&str, u64> {
-    let (input, digits) = take_while(is_digit)(line)?;
-    match digits.parse() {
-        Ok(n) => Ok((input, n)),
-        Err(_) => fail(input),
+/// An integer of any width: an optional sign, then decimal digits or digits after a
+/// `0x` or `0b` prefix. A number too large for `T` is a failure, not a mismatch.
+pub fn parse_int<T: PrimInt>(line: &str) -> IResult<&str, T> {
+    let (rest, negative) = match line.chars().next() {
+        Some('-') => (&line[1..], true),
+        Some('+') => (&line[1..], false),
+        _ => (line, false),
+    };
+    let (digits, radix) = match rest.get(..2) {
+        Some("0x" | "0X") if starts_with_digit(&rest[2..], 16) => (&rest[2..], 16),
+        Some("0b" | "0B") if starts_with_digit(&rest[2..], 2) => (&rest[2..], 2),
+        _ => (rest, 10),
+    };
+    digits_in(line, digits, radix, negative)
+}
+
+/// The number at the start of `digits`, a suffix of `line` after any sign or prefix.
+fn digits_in<'a, T: PrimInt>(
+    line: &'a str,
+    digits: &'a str,
+    radix: u32,
+    negative: bool,
+) -> IResult<&'a str, T> {
+    if !starts_with_digit(digits, radix) {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            line,
+            ErrorKind::Digit,
+        )));
+    }
+
+    let too_large = || nom::Err::Failure(nom::error::Error::new(line, ErrorKind::TooLarge));
+    let base = T::from(radix).ok_or_else(too_large)?;
+    let mut n = T::zero();
+    let mut end = digits.len();
+    for (i, c) in digits.char_indices() {
+        let Some(digit) = c.to_digit(radix) else {
+            end = i;
+            break;
+        };
+        let digit = T::from(digit).ok_or_else(too_large)?;
+        // Negative numbers are built downwards so that `T::min_value()` fits.
+        n = n
+            .checked_mul(&base)
+            .and_then(|n| {
+                if negative {
+                    n.checked_sub(&digit)
+                } else {
+                    n.checked_add(&digit)
+                }
+            })
+            .ok_or_else(too_large)?;
     }
+    Ok((&digits[end..], n))
 }
 
+fn starts_with_digit(s: &str, radix: u32) -> bool {
+    s.chars().next().is_some_and(|c| c.is_digit(radix))
+}
+
+/// Plain decimal digits; unlike [parse_int], no sign or radix prefix.
+pub fn parse_u64(line: &str) -> IResult<&str, u64> {
+    digits_in(line, line, 10, false)
+}
+
+/// Decimal digits with an optional `-`.
 pub fn parse_i64(line: &str) -> IResult<&str, i64> {
-    if let Ok((line, sign)) = alt::<_, _, (_, ErrorKind), _>((char('+'), char('-')))(line) {
-        let (line, number) = parse_u64(line)?;
-        match sign {
-            '+' => Ok((line, number as i64)),
-            '-' => Ok((line, -(number as i64))),
-            _ => unreachable!(),
-        }
-    } else {
-        let (line, number) = parse_u64(line)?;
-        Ok((line, number as i64))
+    match line.strip_prefix('-') {
+        Some(rest) => digits_in(line, rest, 10, true),
+        None => digits_in(line, line, 10, false),
     }
 }
 
+/// Plain decimal digits; unlike [parse_int], no sign or radix prefix.
 pub fn parse_usize(line: &str) -> IResult<&str, usize> {
-    let (input, digits) = take_while(is_digit)(line)?;
-    match digits.parse() {
-        Ok(n) => Ok((input, n)),
-        Err(_) => fail(input),
+    digits_in(line, line, 10, false)
+}
+
+/// Every integer in a free-form line, in order. A `-` only counts as a sign when it
+/// does not follow a letter or digit, so ranges like `2-4` give `[2, 4]`. A number too
+/// large for `T` is an error.
+pub fn ints<T: PrimInt>(line: &str) -> Result<Vec<T>, ParseError> {
+    let mut numbers = vec![];
+    let mut previous = None;
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let starts_number = c.is_ascii_digit()
+            || (c == '-'
+                && starts_with_digit(&rest[1..], 10)
+                && !previous.is_some_and(|p: char| p.is_alphanumeric()));
+        if starts_number {
+            match parse_int(rest) {
+                Ok((next, n)) => {
+                    numbers.push(n);
+                    previous = rest[..rest.len() - next.len()].chars().last();
+                    rest = next;
+                    continue;
+                }
+                Err(e) => return Err(ParseError::from_nom(line, e)),
+            }
+        }
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
     }
+    Ok(numbers)
 }
 
-pub fn parse_dec<T>(line: &str) -> IResult<&str, T>
-where
-    T: FromStr,
-{
-    let (input, digits) = take_while(is_digit)(line)?;
-    match digits.parse::<T>() {
-        Ok(n) => Ok((input, n)),
-        Err(_) => fail(input),
+/// A malformed puzzle input: where parsing stopped and what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The year and day of the puzzle, once the runner knows them.
+    pub puzzle: Option<(u32, usize)>,
+    /// Counted from 1.
+    pub line: usize,
+    /// Counted from 1, in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The whole offending line, without its newline.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error at `rest`, a slice of `input` where parsing stopped.
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len().saturating_sub(rest.len()));
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            puzzle: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            source_line: input[line_start..].lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    /// Converts a nom error from parsing some slice of `input`.
+    pub fn from_nom(input: &str, error: nom::Err<nom::error::Error<&str>>) -> ParseError {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(input, e.input, describe(e.code))
+            }
+            nom::Err::Incomplete(_) => ParseError::at(input, &input[input.len()..], "more input"),
+        }
+    }
+
+    pub fn with_puzzle(mut self, year: u32, day: usize) -> ParseError {
+        self.puzzle = Some((year, day));
+        self
     }
 }
 
+fn describe(kind: ErrorKind) -> String {
+    let description = match kind {
+        ErrorKind::Tag => "a fixed token",
+        ErrorKind::Char => "a specific character",
+        ErrorKind::Digit | ErrorKind::Fail => "a number",
+        ErrorKind::TooLarge => "a number that fits its type",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::OneOf => "one of the allowed characters",
+        ErrorKind::Alt => "one of several alternatives",
+        ErrorKind::Eof => "the end of the line",
+        ErrorKind::SeparatedList | ErrorKind::Many1 => "at least one item",
+        ErrorKind::MapOpt | ErrorKind::MapRes | ErrorKind::Verify => "a valid value",
+        kind => return kind.description().to_lowercase(),
+    };
+    description.to_string()
+}
+
+/// Renders like a compiler diagnostic, quoting the offending line.
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let found = self
+            .source_line
+            .chars()
+            .skip(self.column - 1)
+            .take_while(|c| !c.is_whitespace())
+            .collect::<String>();
+        if found.is_empty() {
+            writeln!(f, "expected {}", self.expected)?;
+        } else {
+            writeln!(f, "expected {}, found {:?}", self.expected, found)?;
+        }
+        let location = match self.puzzle {
+            Some((year, day)) => format!("{} day {} input", year, day),
+            None => String::from("input"),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "{} --> {}:{}:{}",
+            gutter, location, self.line, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{} | {}^ expected {}",
+            gutter,
+            " ".repeat(self.column - 1),
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Checks the result of parsing a slice of `input`, which may only leave whitespace
+/// unparsed. Errors are located within the whole of `input`.
+pub fn finish<'a, T>(input: &'a str, result: IResult<&'a str, T>) -> Result<T, ParseError> {
+    match result {
+        Ok((rest, _)) if !rest.trim().is_empty() => {
+            Err(ParseError::at(input, rest, "the end of the line"))
+        }
+        Ok((_, value)) => Ok(value),
+        Err(error) => Err(ParseError::from_nom(input, error)),
+    }
+}
+
+/// Parses all of `input` with `parser`, failing with a [`ParseError`] if it does not fit.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    finish(input, parser(input))
+}
+
+/// Parses each non-empty line of `input` with `parser`, failing with a [`ParseError`]
+/// for the first line that does not fit.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| finish(input, parser(line)))
+        .collect()
+}
+
+/// Wraps a parser for one line into one for a block of lines, each of which must be
+/// parsed completely.
+pub fn lines_of<'a, T>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    move |block: &'a str| {
+        let mut values = vec![];
+        for line in block.lines() {
+            let (rest, value) = parser(line)?;
+            if !rest.trim().is_empty() {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    rest,
+                    ErrorKind::Eof,
+                )));
+            }
+            values.push(value);
+        }
+        Ok((&block[block.len()..], values))
+    }
+}
+
+/// Splits `input` on blank lines. Each section is a slice of `input` without its
+/// trailing newline, so errors in it are still located within the whole input.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&input[start..end]);
+    }
+    sections
+}
+
+/// A tuple of parsers, one for each section of an input.
+pub trait SectionParsers<'a> {
+    type Output;
+
+    fn parse_sections(
+        &mut self,
+        input: &'a str,
+        sections: &[&'a str],
+    ) -> Result<Self::Output, ParseError>;
+}
+
+macro_rules! section_parsers {
+    ($count:literal; $($parser:ident $value:ident $n:tt),+) => {
+        impl<'a, $($parser, $value),+> SectionParsers<'a> for ($($parser,)+)
+        where
+            $($parser: FnMut(&'a str) -> IResult<&'a str, $value>),+
+        {
+            type Output = ($($value,)+);
+
+            fn parse_sections(
+                &mut self,
+                input: &'a str,
+                sections: &[&'a str],
+            ) -> Result<Self::Output, ParseError> {
+                if sections.len() < $count {
+                    let expected = format!("{} sections separated by blank lines", $count);
+                    return Err(ParseError::at(input, &input[input.len()..], expected));
+                }
+                if let Some(extra) = sections.get($count) {
+                    return Err(ParseError::at(input, extra, "the end of the input"));
+                }
+                Ok(($(finish(input, (self.$n)(sections[$n]))?,)+))
+            }
+        }
+    };
+}
+
+section_parsers!(1; P0 T0 0);
+section_parsers!(2; P0 T0 0, P1 T1 1);
+section_parsers!(3; P0 T0 0, P1 T1 1, P2 T2 2);
+section_parsers!(4; P0 T0 0, P1 T1 1, P2 T2 2, P3 T3 3);
+
+/// Parses each blank-line-separated section of `input` with its own parser, failing with
+/// a [`ParseError`] if a section does not fit or there are too few or too many sections.
+pub fn parse_sections<'a, P: SectionParsers<'a>>(
+    input: &'a str,
+    mut parsers: P,
+) -> Result<P::Output, ParseError> {
+    parsers.parse_sections(input, &sections(input))
+}
+
+/// Parses every blank-line-separated section of `input` with the same parser.
+pub fn parse_each_section<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    sections(input)
+        .into_iter()
+        .map(|section| finish(input, parser(section)))
+        .collect()
+}
+
+/// A value that can fill a `{}` in a [`template`], given the text that follows it.
+pub trait TemplateField<'a>: Sized {
+    fn parse_field(i: &'a str, until: &str) -> IResult<&'a str, Self>;
+}
+
+macro_rules! int_template_fields {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> TemplateField<'a> for $t {
+                fn parse_field(i: &'a str, _until: &str) -> IResult<&'a str, Self> {
+                    parse_int(i)
+                }
+            }
+        )+
+    };
+}
+
+int_template_fields!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Text up to the next literal part of the template on the same line, or to the end of
+/// the line.
+impl<'a> TemplateField<'a> for &'a str {
+    fn parse_field(i: &'a str, until: &str) -> IResult<&'a str, Self> {
+        let (_, line) = take_till(|c| c == '\n')(i)?;
+        take(field_end(line, until))(i)
+    }
+}
+
+/// Where a field starting `line` ends: before `until` if it shows up, otherwise at the
+/// end of the line.
+fn field_end(line: &str, until: &str) -> usize {
+    // Only the part of `until` on this line can show up in it.
+    let stop = until.split('\n').next().unwrap_or("");
+    line.find_substring(stop)
+        .filter(|_| !stop.is_empty())
+        .unwrap_or(line.len())
+}
+
+impl<'a> TemplateField<'a> for String {
+    fn parse_field(i: &'a str, until: &str) -> IResult<&'a str, Self> {
+        <&str>::parse_field(i, until).map(|(i, s)| (i, s.to_string()))
+    }
+}
+
+/// A list separated by `", "`, ending before `until`.
+impl<'a, T: TemplateField<'a>> TemplateField<'a> for Vec<T> {
+    fn parse_field(i: &'a str, until: &str) -> IResult<&'a str, Self> {
+        let (_, line) = take_till(|c| c == '\n')(i)?;
+        let end = field_end(line, until);
+        let (rest, items) = separated_list1(tag(", "), |i| T::parse_field(i, ", "))(&i[..end])?;
+        Ok((&i[end - rest.len()..], items))
+    }
+}
+
+impl<'a> TemplateField<'a> for char {
+    fn parse_field(i: &'a str, _until: &str) -> IResult<&'a str, Self> {
+        anychar(i)
+    }
+}
+
+/// The tuple of fields a [`template`] produces.
+pub trait TemplateFields<'a>: Sized {
+    const COUNT: usize;
+
+    /// Parses the fields, each followed by `literals[n + 1]`.
+    fn parse_fields(i: &'a str, literals: &[&str]) -> IResult<&'a str, Self>;
+}
+
+macro_rules! template_fields {
+    ($count:literal; $($field:ident $n:tt),+) => {
+        impl<'a, $($field: TemplateField<'a>),+> TemplateFields<'a> for ($($field,)+) {
+            const COUNT: usize = $count;
+
+            #[allow(non_snake_case)]
+            fn parse_fields(i: &'a str, literals: &[&str]) -> IResult<&'a str, Self> {
+                $(
+                    let (i, $field) = $field::parse_field(i, literals[$n + 1])?;
+                    let (i, _) = tag(literals[$n + 1])(i)?;
+                )+
+                Ok((i, ($($field,)+)))
+            }
+        }
+    };
+}
+
+template_fields!(1; A 0);
+template_fields!(2; A 0, B 1);
+template_fields!(3; A 0, B 1, C 2);
+template_fields!(4; A 0, B 1, C 2, D 3);
+template_fields!(5; A 0, B 1, C 2, D 3, E 4);
+template_fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Matches a literal part of a format, for code generated by `#[derive(AocParse)]`.
+#[doc(hidden)]
+pub fn literal<'a>(i: &'a str, literal: &str) -> IResult<&'a str, &'a str> {
+    tag(literal)(i)
+}
+
+/// Whether `rest` starts with `until`, or for an empty `until` whether the rest of the
+/// line is blank. This is how a derived enum tells if a variant fits where it stands.
+#[doc(hidden)]
+pub fn followed_by(rest: &str, until: &str) -> bool {
+    if until.is_empty() {
+        rest.lines()
+            .next()
+            .is_none_or(|line| line.trim().is_empty())
+    } else {
+        rest.starts_with(until)
+    }
+}
+
+#[doc(hidden)]
+pub fn no_alternative(i: &str) -> nom::Err<nom::error::Error<&str>> {
+    nom::Err::Error(nom::error::Error::new(i, ErrorKind::Alt))
+}
+
+/// A parser for lines shaped like `template`, where each `{}` is a field of the output
+/// tuple: `template::<(i64, i64)>("x={}, y={}")` parses `"x=3, y=-4"` into `(3, -4)`.
+pub fn template<'a, T: TemplateFields<'a>>(
+    template: &str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> + '_ {
+    let literals = template.split("{}").collect::<Vec<_>>();
+    assert_eq!(
+        literals.len(),
+        T::COUNT + 1,
+        "template {:?} does not have {} fields",
+        template,
+        T::COUNT
+    );
+    move |i: &'a str| {
+        let (i, _) = tag(literals[0])(i)?;
+        T::parse_fields(i, &literals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(parse_int::<i32>("-42 apples"), Ok((" apples", -42)));
+        assert_eq!(parse_int::<u8>("+255,"), Ok((",", 255)));
+        assert_eq!(parse_int::<i8>("-128"), Ok(("", -128)));
+        assert_eq!(parse_int::<u32>("0x1F"), Ok(("", 31)));
+        assert_eq!(parse_int::<i64>("-0b101"), Ok(("", -5)));
+        assert_eq!(parse_int::<u32>("0xg"), Ok(("xg", 0)));
+        assert!(matches!(parse_int::<u8>("256"), Err(nom::Err::Failure(_))));
+        assert!(matches!(parse_int::<u8>("-1"), Err(nom::Err::Failure(_))));
+        assert!(matches!(parse_int::<u64>(""), Err(nom::Err::Error(_))));
+        assert!(matches!(parse_int::<i64>("-"), Err(nom::Err::Error(_))));
+    }
+
+    #[test]
+    fn parses_plain_decimals() {
+        assert_eq!(parse_u64("0x10"), Ok(("x10", 0)));
+        assert_eq!(parse_usize("0b1"), Ok(("b1", 0)));
+        assert_eq!(parse_i64("-12,"), Ok((",", -12)));
+        assert!(matches!(parse_u64("+1"), Err(nom::Err::Error(_))));
+        assert!(matches!(parse_i64("+1"), Err(nom::Err::Error(_))));
+        assert!(matches!(parse_u64("-1"), Err(nom::Err::Error(_))));
+    }
+
+    #[test]
+    fn extracts_integers() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(ints::<i64>(line).unwrap(), [2, -18, -2, 15]);
+        assert_eq!(ints::<u32>("2-4,6-8").unwrap(), [2, 4, 6, 8]);
+        assert!(ints::<u8>("no numbers").unwrap().is_empty());
+        assert_eq!(ints::<u8>("1 300").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "1\n2\n\n\n3\n\n";
+        assert_eq!(sections(input), ["1\n2", "3"]);
+        assert_eq!(
+            parse_each_section(input, lines_of(parse_u64)).unwrap(),
+            [vec![1, 2], vec![3]]
+        );
+        let (first, second) = parse_sections(input, (lines_of(parse_u64), parse_u64)).unwrap();
+        assert_eq!((first, second), (vec![1, 2], 3));
+    }
+
+    #[test]
+    fn locates_errors() {
+        let input = "héllo\nwörld x\n";
+        let error = ParseError::at(input, &input[input.find('x').unwrap()..], "a digit");
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.source_line, "wörld x");
+
+        let error = ParseError::at(input, input, "a digit");
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = ParseError::at(input, &input[input.len()..], "a digit");
+        assert_eq!((error.line, error.column), (3, 1));
+
+        // A copy of the rest is located by its length instead.
+        let rest = String::from("x\n");
+        let error = ParseError::at(input, &rest, "a digit");
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+
+    #[test]
+    fn renders_errors_as_diagnostics() {
+        let input = "1,2\n3;4\n";
+        let error = ParseError::at(input, &input[5..], "a comma").with_puzzle(2021, 7);
+        assert_eq!(
+            error.to_string(),
+            "expected a comma, found \";4\"\n  \
+             --> 2021 day 7 input:2:2\n  \
+             |\n\
+             2 | 3;4\n  \
+             |  ^ expected a comma"
+        );
+
+        let error = ParseError::at(input, &input[3..], "a number");
+        assert_eq!(
+            error.to_string(),
+            "expected a number\n  --> input:1:4\n  |\n1 | 1,2\n  |    ^ expected a number"
+        );
+    }
+
+    #[test]
+    fn locates_errors_in_sections() {
+        let input = "1\n2\n\n3\n4x\n";
+        let error = parse_each_section(input, lines_of(parse_u64)).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 2));
+        assert_eq!(error.expected, "the end of the line");
+    }
+
+    #[test]
+    fn fills_templates() {
+        let mut sensor = template::<(i64, i64, i64, i64)>(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+        );
+        assert_eq!(
+            sensor("Sensor at x=2, y=18: closest beacon is at x=-2, y=15"),
+            Ok(("", (2, 18, -2, 15)))
+        );
+        assert!(sensor("Sensor at x=2, y=18").is_err());
+
+        let mut rule = template::<(&str, char, u32)>("{} => {}{}");
+        assert_eq!(rule("Ca => P3"), Ok(("", ("Ca", 'P', 3))));
+
+        let mut valve = template::<(Vec<&str>, u32)>("tunnels lead to {}; rate={}");
+        assert_eq!(
+            valve("tunnels lead to DD, II; rate=13"),
+            Ok(("", (vec!["DD", "II"], 13)))
+        );
+        let mut sums = template::<(Vec<u32>, u32)>("{} = {}");
+        assert_eq!(sums("1, 2 = 3\n"), Ok(("\n", (vec![1, 2], 3))));
+    }
+
+    #[derive(Debug, PartialEq, aoc_derive::AocParse)]
+    enum Wire {
+        #[aoc(format = "{}")]
+        Signal(u16),
+        #[aoc(format = "{}")]
+        Name(String),
+    }
+
+    #[derive(Debug, PartialEq, aoc_derive::AocParse)]
+    enum Gate {
+        #[aoc(format = "NOT {}")]
+        Not(Wire),
+        #[aoc(format = "{} AND {}")]
+        And(Wire, Wire),
+        #[aoc(format = "{}")]
+        Direct(Wire),
+    }
+
+    #[derive(Debug, PartialEq, aoc_derive::AocParse)]
+    #[aoc(format = "{gate} -> {output}")]
+    struct Connection {
+        gate: Gate,
+        output: String,
+    }
+
+    #[test]
+    fn derives_parsers() {
+        let connection: Connection = "x AND 1 -> y".parse().unwrap();
+        assert_eq!(
+            connection,
+            Connection {
+                gate: Gate::And(Wire::Name("x".into()), Wire::Signal(1)),
+                output: "y".into()
+            }
+        );
+        assert_eq!(
+            Connection::parse("NOT x -> y\nrest"),
+            Ok((
+                "\nrest",
+                Connection {
+                    gate: Gate::Not(Wire::Name("x".into())),
+                    output: "y".into()
+                }
+            ))
+        );
+        assert_eq!(
+            "123 -> b".parse::<Connection>().unwrap().gate,
+            Gate::Direct(Wire::Signal(123))
+        );
+        let error = "x AND y => z".parse::<Connection>().unwrap_err();
+        assert_eq!(error.column, 1);
+    }
+}
@@ -0,0 +1,289 @@
+//! Enumerating and counting ways to split numbers up or pick items out.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+/// Every way to write `total` as an ordered sum of parts within per-part bounds, in
+/// lexicographic order.
+#[derive(Debug, Clone)]
+pub struct Compositions {
+    bounds: Vec<RangeInclusive<usize>>,
+    /// The smallest and largest sums the parts from each index on can make.
+    suffix_min: Vec<usize>,
+    suffix_max: Vec<usize>,
+    next: Option<Vec<usize>>,
+}
+
+/// Every way to write `total` as an ordered sum of `parts` non-negative numbers.
+pub fn compositions(total: usize, parts: usize) -> Compositions {
+    bounded_compositions(total, vec![0..=total; parts])
+}
+
+/// Every way to write `total` as an ordered sum with part `i` in `bounds[i]`.
+pub fn bounded_compositions(total: usize, bounds: Vec<RangeInclusive<usize>>) -> Compositions {
+    let mut suffix_min = vec![0usize; bounds.len() + 1];
+    let mut suffix_max = vec![0usize; bounds.len() + 1];
+    for (i, bound) in bounds.iter().enumerate().rev() {
+        suffix_min[i] = suffix_min[i + 1] + bound.start();
+        suffix_max[i] = suffix_max[i + 1].saturating_add(*bound.end());
+    }
+    let mut compositions = Compositions {
+        next: Some(vec![0; bounds.len()]),
+        bounds,
+        suffix_min,
+        suffix_max,
+    };
+    if !compositions.fill(0, total) {
+        compositions.next = None;
+    }
+    compositions
+}
+
+impl Compositions {
+    /// Makes the parts from `from` on the smallest composition of `rest`, if there is one.
+    fn fill(&mut self, from: usize, mut rest: usize) -> bool {
+        if !(self.suffix_min[from]..=self.suffix_max[from]).contains(&rest) {
+            return false;
+        }
+        let parts = self.next.as_mut().unwrap();
+        for (i, part) in parts.iter_mut().enumerate().skip(from) {
+            // Take as little as possible while the later parts can still absorb the rest.
+            *part = (*self.bounds[i].start()).max(rest.saturating_sub(self.suffix_max[i + 1]));
+            rest -= *part;
+        }
+        true
+    }
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.next.clone()?;
+        // Bump the rightmost part that can grow, then refill everything after it.
+        let mut remaining = 0;
+        for i in (0..current.len()).rev() {
+            remaining += current[i];
+            let grown = current[i] + 1;
+            if grown <= *self.bounds[i].end()
+                && grown <= remaining
+                && self.fill(i + 1, remaining - grown)
+            {
+                self.next.as_mut().unwrap()[i] = grown;
+                return Some(current);
+            }
+        }
+        self.next = None;
+        Some(current)
+    }
+}
+
+/// How many subsets of `items` add up to `target`, indexed by the number of items used.
+pub fn subset_sum_counts_by_size(items: &[usize], target: usize) -> Vec<u64> {
+    // counts[k][s]: the subsets of the items so far with k items adding up to s.
+    let mut counts = vec![vec![0u64; target + 1]; items.len() + 1];
+    counts[0][0] = 1;
+    for (seen, &item) in items.iter().enumerate() {
+        for k in (0..=seen).rev() {
+            for s in (0..=target.saturating_sub(item)).rev() {
+                counts[k + 1][s + item] += counts[k][s];
+            }
+        }
+    }
+    counts.into_iter().map(|by_sum| by_sum[target]).collect()
+}
+
+/// How many subsets of `items` add up to `target`.
+pub fn count_subsets_with_sum(items: &[usize], target: usize) -> u64 {
+    let mut counts = vec![0u64; target + 1];
+    counts[0] = 1;
+    for &item in items {
+        for s in (item..=target).rev() {
+            counts[s] += counts[s - item];
+        }
+    }
+    counts[target]
+}
+
+/// The indices of every subset of `items` adding up to `target`.
+pub fn subsets_with_sum(items: &[usize], target: usize) -> Vec<Vec<usize>> {
+    let mut subsets = vec![];
+    collect_subsets(items, 0, target, None, &mut vec![], &mut subsets);
+    subsets
+}
+
+/// The indices of every subset of exactly `size` items adding up to `target`.
+pub fn k_subsets_with_sum(items: &[usize], size: usize, target: usize) -> Vec<Vec<usize>> {
+    let mut subsets = vec![];
+    collect_subsets(items, 0, target, Some(size), &mut vec![], &mut subsets);
+    subsets
+}
+
+/// The subsets adding up to `target` that use the fewest items, or none if no subset does.
+pub fn smallest_subsets_with_sum(items: &[usize], target: usize) -> Vec<Vec<usize>> {
+    (0..=items.len())
+        .map(|size| k_subsets_with_sum(items, size, target))
+        .find(|subsets| !subsets.is_empty())
+        .unwrap_or_default()
+}
+
+fn collect_subsets(
+    items: &[usize],
+    from: usize,
+    rest: usize,
+    size: Option<usize>,
+    chosen: &mut Vec<usize>,
+    subsets: &mut Vec<Vec<usize>>,
+) {
+    if rest == 0 && size.is_none_or(|size| chosen.len() == size) {
+        subsets.push(chosen.clone());
+    }
+    if size.is_some_and(|size| chosen.len() >= size) {
+        return;
+    }
+    for i in from..items.len() {
+        if items[i] <= rest {
+            chosen.push(i);
+            collect_subsets(items, i + 1, rest - items[i], size, chosen, subsets);
+            chosen.pop();
+        }
+    }
+}
+
+/// Whether some subset of `items` adds up to `target` and is accepted by `accept`, which
+/// gets its indices. The search stops at the first accepted subset.
+pub fn any_subset_with_sum(
+    items: &[usize],
+    target: usize,
+    mut accept: impl FnMut(&[usize]) -> bool,
+) -> bool {
+    find_subset(items, 0, target, &mut vec![], &mut accept)
+}
+
+fn find_subset(
+    items: &[usize],
+    from: usize,
+    rest: usize,
+    chosen: &mut Vec<usize>,
+    accept: &mut impl FnMut(&[usize]) -> bool,
+) -> bool {
+    if rest == 0 && accept(chosen) {
+        return true;
+    }
+    for i in from..items.len() {
+        if items[i] <= rest {
+            chosen.push(i);
+            let found = find_subset(items, i + 1, rest - items[i], chosen, accept);
+            chosen.pop();
+            if found {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Every subset of `size` items, as indices, from the smallest total to the largest.
+pub fn k_subsets_by_sum(items: &[usize], size: usize) -> impl Iterator<Item = Vec<usize>> {
+    let order: Vec<usize> = {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        order.sort_by_key(|&i| items[i]);
+        order
+    };
+    let sorted: Vec<usize> = order.iter().map(|&i| items[i]).collect();
+    fn total(sorted: &[usize], positions: &[usize]) -> usize {
+        positions.iter().map(|&p| sorted[p]).sum()
+    }
+
+    // Positions in the sorted items; each popped subset queues the ones with one pick
+    // moved a step towards the larger items.
+    let mut heap = BinaryHeap::new();
+    let mut seen = HashSet::new();
+    if size <= items.len() {
+        let first: Vec<usize> = (0..size).collect();
+        heap.push(Reverse((total(&sorted, &first), first.clone())));
+        seen.insert(first);
+    }
+    std::iter::from_fn(move || {
+        let Reverse((_, positions)) = heap.pop()?;
+        for i in 0..size {
+            let limit = positions.get(i + 1).copied().unwrap_or(sorted.len());
+            if positions[i] + 1 < limit {
+                let mut next = positions.clone();
+                next[i] += 1;
+                if seen.insert(next.clone()) {
+                    heap.push(Reverse((total(&sorted, &next), next)));
+                }
+            }
+        }
+        Some(positions.iter().map(|&p| order[p]).sorted().collect())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_compositions() {
+        let all: Vec<_> = compositions(2, 3).collect();
+        assert_eq!(
+            all,
+            [
+                [0, 0, 2],
+                [0, 1, 1],
+                [0, 2, 0],
+                [1, 0, 1],
+                [1, 1, 0],
+                [2, 0, 0]
+            ]
+        );
+        assert_eq!(compositions(100, 4).count(), 176851);
+
+        let bounded: Vec<_> = bounded_compositions(5, vec![1..=2, 0..=1, 2..=9]).collect();
+        assert_eq!(bounded, [[1, 0, 4], [1, 1, 3], [2, 0, 3], [2, 1, 2]]);
+        assert_eq!(bounded_compositions(9, vec![0..=2, 0..=2]).count(), 0);
+        assert_eq!(compositions(0, 0).count(), 1);
+    }
+
+    #[test]
+    fn counts_subset_sums() {
+        let containers = [20, 15, 10, 5, 5];
+        assert_eq!(count_subsets_with_sum(&containers, 25), 4);
+        assert_eq!(
+            subset_sum_counts_by_size(&containers, 25),
+            [0, 0, 3, 1, 0, 0]
+        );
+        assert_eq!(
+            subsets_with_sum(&containers, 25),
+            [vec![0, 3], vec![0, 4], vec![1, 2], vec![1, 3, 4]]
+        );
+        assert_eq!(k_subsets_with_sum(&containers, 3, 25), [vec![1, 3, 4]]);
+        assert_eq!(smallest_subsets_with_sum(&containers, 25).len(), 3);
+        assert!(smallest_subsets_with_sum(&containers, 100).is_empty());
+
+        let mut seen = vec![];
+        let found = any_subset_with_sum(&containers, 25, |subset| {
+            seen.push(subset.to_vec());
+            subset.len() == 2
+        });
+        assert!(found);
+        assert_eq!(seen, [vec![0, 3]]);
+        assert!(!any_subset_with_sum(&containers, 25, |subset| subset.len() > 3));
+        assert!(!any_subset_with_sum(&containers, 100, |_| true));
+    }
+
+    #[test]
+    fn orders_subsets_by_sum() {
+        let items = [5, 1, 4, 2];
+        let sums = k_subsets_by_sum(&items, 2)
+            .map(|subset| subset.iter().map(|&i| items[i]).sum::<usize>())
+            .collect::<Vec<_>>();
+        assert_eq!(sums, [3, 5, 6, 6, 7, 9]);
+        assert_eq!(k_subsets_by_sum(&items, 2).next(), Some(vec![1, 3]));
+        assert_eq!(k_subsets_by_sum(&items, 5).count(), 0);
+    }
+}
@@ -0,0 +1,243 @@
+//! Graphs over named nodes, interned to dense ids.
+
+use std::collections::HashMap;
+use std::ops::Add;
+
+/// Interns names as ids `0, 1, 2, ...` in the order they are first added.
+#[derive(Debug, Clone, Default)]
+pub struct NodeRegistration {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+}
+
+impl NodeRegistration {
+    pub fn add(&mut self, name: &str) -> usize {
+        if self.ids.contains_key(name) {
+            return self.ids[name];
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    pub fn get_name(&self, id: usize) -> Option<&str> {
+        if id < self.names.len() {
+            Some(&self.names[id])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.names.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for NodeRegistration {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut reg = NodeRegistration::default();
+        for s in iter {
+            reg.add(s);
+        }
+        reg
+    }
+}
+
+/// A weighted graph stored as adjacency lists. Edges are directed; an undirected edge
+/// is a pair of directed ones.
+#[derive(Debug, Clone)]
+pub struct Graph<W> {
+    nodes: NodeRegistration,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph {
+            nodes: NodeRegistration::default(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<W: Copy> Graph<W> {
+    pub fn new() -> Graph<W> {
+        Graph::default()
+    }
+
+    pub fn add_node(&mut self, name: &str) -> usize {
+        let id = self.nodes.add(name);
+        if id == self.edges.len() {
+            self.edges.push(vec![]);
+        }
+        id
+    }
+
+    /// Adds an edge from `from` to `to`, adding either node if it is new.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push((to, weight));
+    }
+
+    /// Adds an edge each way between `a` and `b`.
+    pub fn add_undirected_edge(&mut self, a: &str, b: &str, weight: W) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn nodes(&self) -> &NodeRegistration {
+        &self.nodes
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.nodes.get_id(name)
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        self.nodes.get_name(id).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The edges leaving a node, as `(to, weight)`.
+    pub fn neighbors(&self, id: usize) -> &[(usize, W)] {
+        &self.edges[id]
+    }
+
+    /// The weight of the first edge from `from` to `to`.
+    pub fn weight(&self, from: usize, to: usize) -> Option<W> {
+        self.edges[from]
+            .iter()
+            .find(|&&(n, _)| n == to)
+            .map(|&(_, w)| w)
+    }
+
+    /// `matrix[from][to]` holds the weight of the edge between them, if any.
+    pub fn adjacency_matrix(&self) -> Vec<Vec<Option<W>>> {
+        let mut matrix = vec![vec![None; self.len()]; self.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, weight) in edges {
+                matrix[from][to].get_or_insert(weight);
+            }
+        }
+        matrix
+    }
+
+    /// The nodes reachable from each other when edge directions are ignored, each group
+    /// sorted by id.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut undirected = vec![vec![]; self.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                undirected[from].push(to);
+                undirected[to].push(from);
+            }
+        }
+        let mut component = vec![None; self.len()];
+        let mut components = vec![];
+        for start in 0..self.len() {
+            if component[start].is_some() {
+                continue;
+            }
+            let mut members = vec![];
+            let mut stack = vec![start];
+            component[start] = Some(components.len());
+            while let Some(node) = stack.pop() {
+                members.push(node);
+                for &next in &undirected[node] {
+                    if component[next].is_none() {
+                        component[next] = Some(components.len());
+                        stack.push(next);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(members);
+        }
+        components
+    }
+}
+
+impl<W: Copy + Ord + Default + Add<Output = W>> Graph<W> {
+    /// The shortest distance between every pair of nodes by Floyd–Warshall, `None` where
+    /// there is no path. Weights must not be negative.
+    pub fn all_pairs_shortest_paths(&self) -> Vec<Vec<Option<W>>> {
+        let mut distances = self.adjacency_matrix();
+        for (node, row) in distances.iter_mut().enumerate() {
+            row[node] = Some(W::default());
+        }
+        for k in 0..self.len() {
+            // Paths through `k` never shorten the ones starting at `k`.
+            let from_k = distances[k].clone();
+            for row in distances.iter_mut() {
+                let Some(to_k) = row[k] else {
+                    continue;
+                };
+                for (d, via) in row.iter_mut().zip(&from_k) {
+                    if let Some(via) = via {
+                        let through_k = to_k + *via;
+                        if d.is_none_or(|d| through_k < d) {
+                            *d = Some(through_k);
+                        }
+                    }
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Graph<u32> {
+        let mut graph = Graph::new();
+        graph.add_undirected_edge("a", "b", 1);
+        graph.add_edge("b", "c", 5);
+        graph.add_edge("a", "c", 10);
+        graph.add_undirected_edge("x", "y", 2);
+        graph
+    }
+
+    #[test]
+    fn interns_names() {
+        let graph = sample();
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.name(3), "x");
+        assert_eq!(graph.neighbors(0), [(1, 1), (2, 10)]);
+        assert_eq!(graph.weight(2, 1), None);
+        assert_eq!(graph.adjacency_matrix()[1][0], Some(1));
+    }
+
+    #[test]
+    fn finds_shortest_paths_and_components() {
+        let graph = sample();
+        let distances = graph.all_pairs_shortest_paths();
+        assert_eq!(distances[0][2], Some(6));
+        assert_eq!(distances[2][0], None);
+        assert_eq!(distances[3][3], Some(0));
+        assert_eq!(distances[0][4], None);
+        assert_eq!(graph.components(), [vec![0, 1, 2], vec![3, 4]]);
+    }
+}
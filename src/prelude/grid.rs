@@ -0,0 +1,362 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use super::point::Point2;
+use crate::parsers::ParseError;
+
+/// A position in a [`Grid`], counted from the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub fn new(row: usize, col: usize) -> Coord {
+        Coord { row, col }
+    }
+
+    /// Moves by a signed offset, or returns `None` when that leaves the first quadrant.
+    pub fn offset(&self, d_row: isize, d_col: isize) -> Option<Coord> {
+        Some(Coord::new(
+            self.row.checked_add_signed(d_row)?,
+            self.col.checked_add_signed(d_col)?,
+        ))
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((row, col): (usize, usize)) -> Self {
+        Coord::new(row, col)
+    }
+}
+
+const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangle of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows have different lengths"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one row per non-empty line, converting each byte with `cell`.
+    ///
+    /// Fails with a [`ParseError`] at the first row whose length differs from the first one.
+    pub fn parse(input: &str, mut cell: impl FnMut(u8) -> T) -> Result<Grid<T>, ParseError> {
+        let mut lines = input.lines().filter(|line| !line.is_empty()).peekable();
+        let width = lines.peek().map_or(0, |line| line.len());
+        let rows = lines
+            .map(|line| {
+                if line.len() != width {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("a row of {} cells", width),
+                    ));
+                }
+                Ok(line.bytes().map(&mut cell).collect())
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.height && coord.col < self.width
+    }
+
+    /// The cell at a [`Coord`] or [`Point2`], if it is inside the grid.
+    pub fn get(&self, at: impl TryInto<Coord>) -> Option<&T> {
+        let coord = at.try_into().ok()?;
+        self.contains(coord)
+            .then(|| &self.cells[coord.row * self.width + coord.col])
+    }
+
+    pub fn get_mut(&mut self, at: impl TryInto<Coord>) -> Option<&mut T> {
+        let coord = at.try_into().ok()?;
+        if self.contains(coord) {
+            Some(&mut self.cells[coord.row * self.width + coord.col])
+        } else {
+            None
+        }
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height * width).map(move |i| Coord::new(i / width, i % width))
+    }
+
+    /// Every cell with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |col| self.col(col))
+    }
+
+    /// The coordinate of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        let i = self.cells.iter().position(predicate)?;
+        Some(Coord::new(i / self.width, i % self.width))
+    }
+
+    /// The orthogonal neighbors of a cell that lie inside the grid.
+    pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbors(coord, &NEIGHBORS4)
+    }
+
+    /// The orthogonal and diagonal neighbors of a cell that lie inside the grid.
+    pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.neighbors(coord, &NEIGHBORS8)
+    }
+
+    fn neighbors(
+        &self,
+        coord: Coord,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Coord> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&(d_row, d_col)| coord.offset(d_row, d_col))
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Builds a grid of the given size from a function of each coordinate.
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Coord) -> T) -> Grid<T> {
+        let cells = (0..width * height)
+            .map(|i| Coord::new(i / width, i % width))
+            .map(f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coord::new(c.col, c.row)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coord::new(self.height - 1 - c.col, c.row)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter counter-clockwise.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coord::new(c.col, self.width - 1 - c.row)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |c| {
+            self[Coord::new(c.row, self.width - 1 - c.col)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |c| {
+            self[Coord::new(self.height - 1 - c.row, c.col)].clone()
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "{:?} out of bounds for a {}x{} grid",
+                coord, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} out of bounds for a {}x{} grid", coord, width, height))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2) -> &T {
+        self.get(p).unwrap_or_else(|| {
+            panic!(
+                "{} out of bounds for a {}x{} grid",
+                p, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, p: Point2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} out of bounds for a {}x{} grid", p, width, height))
+    }
+}
+
+/// Prints each cell next to each other, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", char::from).unwrap()
+    }
+
+    #[test]
+    fn parses_and_indexes() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(1, 2)], 'f');
+        assert_eq!(grid.get(Coord::new(2, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 3)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Coord::new(1, 1)));
+    }
+
+    #[test]
+    fn locates_ragged_rows() {
+        let error = Grid::parse("abc\nde\nfgh\n", char::from).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of 3 cells");
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.col(1).rev().collect::<String>(), "eb");
+        assert_eq!(grid.cols().map(|col| col.count()).sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn finds_neighbors_inside_the_grid() {
+        let grid = sample();
+        let corner = grid.neighbors4(Coord::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Coord::new(0, 1), Coord::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Coord::new(0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8(Coord::new(1, 2)).count(), 3);
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = sample();
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
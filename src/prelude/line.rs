@@ -0,0 +1,331 @@
+use super::math::gcd;
+use super::point::Point2;
+
+fn cross(a: Point2, b: Point2) -> i64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Point2, b: Point2) -> i64 {
+    a.x * b.x + a.y * b.y
+}
+
+/// The range of `i` for which `start + step * i` lies in `low..=high`, unbounded ends
+/// given as `i64::MIN`/`i64::MAX`.
+fn steps_within(start: i64, step: i64, low: i64, high: i64) -> Option<(i64, i64)> {
+    if step == 0 {
+        return (low..=high)
+            .contains(&start)
+            .then_some((i64::MIN, i64::MAX));
+    }
+    let (low, high) = if step > 0 {
+        (low - start, high - start)
+    } else {
+        (start - high, start - low)
+    };
+    let step = step.abs();
+    Some((-(-low).div_euclid(step), high.div_euclid(step)))
+}
+
+/// Where two segments meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intersection {
+    Point(Point2),
+    /// The segments are collinear and share this piece.
+    Overlap(LineSegment),
+}
+
+impl Intersection {
+    /// The lattice points the segments have in common.
+    pub fn points(&self) -> Vec<Point2> {
+        match self {
+            Intersection::Point(p) => vec![*p],
+            Intersection::Overlap(segment) => segment.points().collect(),
+        }
+    }
+}
+
+/// A segment between two integer points, endpoints included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineSegment {
+    p1: Point2,
+    p2: Point2,
+}
+
+impl LineSegment {
+    pub fn new(p1: Point2, p2: Point2) -> LineSegment {
+        LineSegment { p1, p2 }
+    }
+
+    pub fn start(&self) -> Point2 {
+        self.p1
+    }
+
+    pub fn end(&self) -> Point2 {
+        self.p2
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.p1.y == self.p2.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.p1.x == self.p2.x
+    }
+
+    pub fn dx(&self) -> i64 {
+        self.p2.x - self.p1.x
+    }
+
+    pub fn dy(&self) -> i64 {
+        self.p2.y - self.p1.y
+    }
+
+    fn direction(&self) -> Point2 {
+        self.p2 - self.p1
+    }
+
+    /// The top-left and bottom-right corners of the smallest box around the segment, with
+    /// `y` growing south as for [`Point2`].
+    pub fn bounding_box(&self) -> (Point2, Point2) {
+        (
+            Point2::new(self.p1.x.min(self.p2.x), self.p1.y.min(self.p2.y)),
+            Point2::new(self.p1.x.max(self.p2.x), self.p1.y.max(self.p2.y)),
+        )
+    }
+
+    /// The number of steps between lattice points, and one such step.
+    fn lattice_step(&self) -> (i64, Point2) {
+        let steps = gcd(self.dx().unsigned_abs(), self.dy().unsigned_abs()) as i64;
+        if steps == 0 {
+            (0, Point2::ORIGIN)
+        } else {
+            (steps, Point2::new(self.dx() / steps, self.dy() / steps))
+        }
+    }
+
+    /// Every integer point lying exactly on the segment, from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let (steps, step) = self.lattice_step();
+        let start = self.p1;
+        (0..=steps).map(move |i| start + step * i)
+    }
+
+    /// The piece of the segment inside the box from `top_left` to `bottom_right`, edges
+    /// included, keeping the segment's direction. Its ends are the first and last integer
+    /// points of the segment in the box, so it is `None` if there are none.
+    pub fn clip(&self, top_left: Point2, bottom_right: Point2) -> Option<LineSegment> {
+        let (steps, step) = self.lattice_step();
+        let (x_first, x_last) = steps_within(self.p1.x, step.x, top_left.x, bottom_right.x)?;
+        let (y_first, y_last) = steps_within(self.p1.y, step.y, top_left.y, bottom_right.y)?;
+        let (first, last) = (x_first.max(y_first).max(0), x_last.min(y_last).min(steps));
+        (first <= last).then(|| LineSegment::new(self.p1 + step * first, self.p1 + step * last))
+    }
+
+    /// The connected run of cells that best approximates the segment, from start to end,
+    /// by Bresenham's algorithm.
+    pub fn bresenham(&self) -> Vec<Point2> {
+        let (dx, dy) = (self.dx().abs(), -self.dy().abs());
+        let step = self.direction().signum();
+        let mut error = dx + dy;
+        let mut p = self.p1;
+        let mut points = vec![p];
+        while p != self.p2 {
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                p.x += step.x;
+            }
+            if doubled <= dx {
+                error += dx;
+                p.y += step.y;
+            }
+            points.push(p);
+        }
+        points
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        let r = self.direction();
+        let offset = p - self.p1;
+        if r == Point2::ORIGIN {
+            return offset == Point2::ORIGIN;
+        }
+        cross(offset, r) == 0 && (0..=dot(r, r)).contains(&dot(offset, r))
+    }
+
+    fn boxes_overlap(&self, other: &LineSegment) -> bool {
+        let ((min_a, max_a), (min_b, max_b)) = (self.bounding_box(), other.bounding_box());
+        min_a.x <= max_b.x && min_b.x <= max_a.x && min_a.y <= max_b.y && min_b.y <= max_a.y
+    }
+
+    /// Whether the segments touch anywhere, including between integer points.
+    pub fn intersects(&self, other: &LineSegment) -> bool {
+        if !self.boxes_overlap(other) {
+            return false;
+        }
+        let side = |s: &LineSegment, p: Point2| cross(s.direction(), p - s.p1).signum();
+        let (a1, a2) = (side(self, other.p1), side(self, other.p2));
+        let (b1, b2) = (side(other, self.p1), side(other, self.p2));
+        if a1 == 0 && a2 == 0 {
+            // Collinear, and the bounding boxes already overlap.
+            return true;
+        }
+        a1 != a2 && b1 != b2
+    }
+
+    /// The shared piece of two collinear segments.
+    pub fn overlap(&self, other: &LineSegment) -> Option<LineSegment> {
+        match self.lattice_intersection(other)? {
+            Intersection::Overlap(segment) => Some(segment),
+            Intersection::Point(p) if cross(self.direction(), other.direction()) == 0 => {
+                Some(LineSegment::new(p, p))
+            }
+            Intersection::Point(_) => None,
+        }
+    }
+
+    /// Where the segments meet on integer points. Segments that only cross between
+    /// integer points have no lattice intersection.
+    pub fn lattice_intersection(&self, other: &LineSegment) -> Option<Intersection> {
+        if !self.boxes_overlap(other) {
+            return None;
+        }
+        let (r, s) = (self.direction(), other.direction());
+        if r == Point2::ORIGIN {
+            return other
+                .contains(self.p1)
+                .then_some(Intersection::Point(self.p1));
+        }
+        if s == Point2::ORIGIN {
+            return self
+                .contains(other.p1)
+                .then_some(Intersection::Point(other.p1));
+        }
+
+        let offset = other.p1 - self.p1;
+        let denominator = cross(r, s);
+        if denominator == 0 {
+            if cross(offset, r) != 0 {
+                return None;
+            }
+            // Collinear: order the four endpoints along `r` and keep the middle piece.
+            let key = |p: &Point2| dot(*p - self.p1, r);
+            let (a_lo, a_hi) = (self.p1, self.p2);
+            let (b_lo, b_hi) = if key(&other.p1) <= key(&other.p2) {
+                (other.p1, other.p2)
+            } else {
+                (other.p2, other.p1)
+            };
+            let lo = if key(&a_lo) >= key(&b_lo) { a_lo } else { b_lo };
+            let hi = if key(&a_hi) <= key(&b_hi) { a_hi } else { b_hi };
+            return match key(&lo).cmp(&key(&hi)) {
+                std::cmp::Ordering::Less => Some(Intersection::Overlap(LineSegment::new(lo, hi))),
+                std::cmp::Ordering::Equal => Some(Intersection::Point(lo)),
+                std::cmp::Ordering::Greater => None,
+            };
+        }
+
+        // Solve self.p1 + r * t / d == other.p1 + s * u / d with 0 <= t, u <= d.
+        let sign = denominator.signum();
+        let (t, u, d) = (
+            cross(offset, s) * sign,
+            cross(offset, r) * sign,
+            denominator.abs(),
+        );
+        if !(0..=d).contains(&t) || !(0..=d).contains(&u) {
+            return None;
+        }
+        if (r.x * t) % d != 0 || (r.y * t) % d != 0 {
+            return None;
+        }
+        Some(Intersection::Point(Point2::new(
+            self.p1.x + r.x * t / d,
+            self.p1.y + r.y * t / d,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> LineSegment {
+        LineSegment::new(Point2::new(x1, y1), Point2::new(x2, y2))
+    }
+
+    #[test]
+    fn rasterizes_any_slope() {
+        let points: Vec<_> = segment(0, 0, 6, -4).points().collect();
+        assert_eq!(
+            points,
+            [Point2::new(0, 0), Point2::new(3, -2), Point2::new(6, -4)]
+        );
+        assert_eq!(segment(3, 3, 0, 0).points().count(), 4);
+        assert_eq!(segment(1, 1, 1, 1).points().count(), 1);
+
+        let cells = segment(0, 0, 4, 2).bresenham();
+        assert_eq!(cells.len(), 5);
+        assert_eq!(cells.first(), Some(&Point2::new(0, 0)));
+        assert_eq!(cells.last(), Some(&Point2::new(4, 2)));
+        assert!(cells.windows(2).all(|w| w[0].chebyshev(w[1]) == 1));
+    }
+
+    #[test]
+    fn intersects_crossing_segments() {
+        let (a, b) = (segment(0, 0, 4, 4), segment(0, 4, 4, 0));
+        assert!(a.intersects(&b));
+        assert_eq!(
+            a.lattice_intersection(&b),
+            Some(Intersection::Point(Point2::new(2, 2)))
+        );
+
+        // These cross at (0.5, 0.5).
+        let (a, b) = (segment(0, 0, 1, 1), segment(0, 1, 1, 0));
+        assert!(a.intersects(&b));
+        assert_eq!(a.lattice_intersection(&b), None);
+
+        assert!(!segment(0, 0, 1, 0).intersects(&segment(0, 1, 1, 1)));
+    }
+
+    #[test]
+    fn clips_to_a_box() {
+        let (top_left, bottom_right) = (Point2::new(1, -5), Point2::new(10, 0));
+        assert_eq!(
+            segment(0, 0, 6, -4).clip(top_left, bottom_right),
+            Some(segment(3, -2, 6, -4))
+        );
+        assert_eq!(
+            segment(10, 2, 0, 2).clip(Point2::new(3, 0), Point2::new(5, 5)),
+            Some(segment(5, 2, 3, 2))
+        );
+        assert_eq!(
+            segment(1, 1, 1, 1).clip(Point2::ORIGIN, Point2::new(2, 2)),
+            Some(segment(1, 1, 1, 1))
+        );
+        assert_eq!(
+            segment(0, 0, 4, 0).clip(Point2::new(0, 1), Point2::new(4, 4)),
+            None
+        );
+        // The segment passes through the box, but between its integer points.
+        assert_eq!(
+            segment(0, 0, 6, -4).clip(Point2::new(1, -1), Point2::new(2, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_overlaps() {
+        let (a, b) = (segment(0, 0, 6, 0), segment(8, 0, 3, 0));
+        assert_eq!(a.overlap(&b), Some(segment(3, 0, 6, 0)));
+        assert_eq!(
+            segment(0, 0, 2, 2).overlap(&segment(2, 2, 5, 5)),
+            Some(segment(2, 2, 2, 2))
+        );
+        assert_eq!(segment(0, 0, 2, 0).overlap(&segment(3, 0, 5, 0)), None);
+        assert_eq!(segment(0, 0, 2, 2).overlap(&segment(0, 2, 2, 0)), None);
+        assert_eq!(
+            segment(0, 0, 4, 0).bounding_box(),
+            (Point2::new(0, 0), Point2::new(4, 0))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Number theory: divisibility, modular arithmetic and sieves.
+
+use num_traits::{PrimInt, Signed};
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple, or 0 when either number is 0.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// The gcd of all the numbers, 0 for none.
+pub fn gcd_of(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// The lcm of all the numbers, 1 for none.
+pub fn lcm_of(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+/// Returns `(g, x, y)` such that `g = gcd(a, b) >= 0` and `a * x + b * y = g`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves a system of `x ≡ residue (mod modulus)`, returning `(x, lcm of the moduli)`
+/// with `x` in `0..lcm`. The moduli need not be coprime; `None` means no solution.
+///
+/// The result is an `i128`, since the lcm of several `i64` moduli soon outgrows `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i128, i128)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for (residue, modulus) in congruences {
+        let (a, n) = (residue as i128, modulus as i128);
+        let (g, p, _) = extended_gcd(m, n);
+        if (a - x) % g != 0 {
+            return None;
+        }
+        // Step x by multiples of m until it also fits the new congruence.
+        let step = n / g;
+        let k = ((a - x) / g % step * p).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        x = x.rem_euclid(m);
+    }
+    Some((x, m))
+}
+
+/// `base` to the power of `exp`, modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let (mut base, mut exp, mut result) = (base as u128 % modulus, exp, 1 % modulus);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Every prime below `limit`, by the sieve of Eratosthenes.
+pub fn primes_below(limit: usize) -> Vec<usize> {
+    let mut is_prime = vec![true; limit];
+    let mut primes = vec![];
+    for n in 2..limit {
+        if is_prime[n] {
+            primes.push(n);
+            for multiple in (n * n..limit).step_by(n) {
+                is_prime[multiple] = false;
+            }
+        }
+    }
+    primes
+}
+
+/// The sum of the divisors of every number below `limit`, indexed by the number.
+pub fn divisor_sums(limit: usize) -> Vec<u64> {
+    divisor_sums_capped(limit, usize::MAX)
+}
+
+/// Like [`divisor_sums`], but each divisor `d` only counts towards its first `multiples`
+/// multiples `d, 2d, ...`.
+pub fn divisor_sums_capped(limit: usize, multiples: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit];
+    for d in 1..limit {
+        for n in (d..limit).step_by(d).take(multiples) {
+            sums[n] += d as u64;
+        }
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_common_divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(gcd_of([24, 36, 60]), 12);
+        assert_eq!(lcm_of([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_of([]), 1);
+
+        let (g, x, y) = extended_gcd(240, -46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x - 46 * y, 2);
+        let (a, b) = (i64::MAX as i128 * 6, i64::MAX as i128 * 4);
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, i64::MAX as i128 * 2);
+        assert_eq!(a * x + b * y, g);
+    }
+
+    #[test]
+    fn does_modular_arithmetic() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+
+        // The moduli only fit an i64 one at a time.
+        let (p, q) = (1_000_000_000_039, 1_000_000_000_037);
+        let (x, m) = crt([(1, p), (2, q)]).unwrap();
+        assert_eq!(m, p as i128 * q as i128);
+        assert_eq!((x % p as i128, x % q as i128), (1, 2));
+    }
+
+    #[test]
+    fn sieves() {
+        assert_eq!(primes_below(20), [2, 3, 5, 7, 11, 13, 17, 19]);
+        assert_eq!(divisor_sums(9), [0, 1, 3, 4, 7, 6, 12, 8, 15]);
+        assert_eq!(divisor_sums_capped(9, 2), [0, 1, 3, 3, 6, 5, 9, 7, 12]);
+    }
+}
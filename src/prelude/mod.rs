@@ -0,0 +1,131 @@
+pub use itertools::Itertools;
+pub use std::cmp::Reverse;
+pub use std::collections::BinaryHeap;
+pub use std::collections::HashMap;
+pub use std::collections::HashSet;
+pub use std::collections::VecDeque;
+pub use std::fmt::Debug;
+pub use std::io::Read;
+
+pub use itertools::iproduct;
+pub use itertools::MinMaxResult;
+pub use ndarray::prelude::*;
+pub use ndarray::Array;
+pub use nom::branch::alt;
+pub use nom::bytes::complete::is_a;
+pub use nom::bytes::complete::tag;
+pub use nom::bytes::complete::take;
+pub use nom::bytes::complete::take_till;
+pub use nom::bytes::complete::take_while;
+pub use nom::character::complete::alpha0;
+pub use nom::character::complete::alpha1;
+pub use nom::character::complete::alphanumeric0;
+pub use nom::character::complete::char;
+pub use nom::character::complete::digit0;
+pub use nom::character::complete::digit1;
+pub use nom::character::complete::multispace0;
+pub use nom::character::complete::space0;
+pub use nom::character::complete::space1;
+pub use nom::character::is_alphabetic;
+pub use nom::character::is_alphanumeric;
+pub use nom::character::is_digit;
+pub use nom::combinator::fail;
+pub use nom::combinator::map_opt;
+pub use nom::multi::many0;
+pub use nom::multi::many1;
+pub use nom::multi::separated_list0;
+pub use nom::multi::separated_list1;
+pub use nom::number::complete::i64;
+pub use nom::number::complete::u64;
+pub use nom::sequence::delimited;
+pub use nom::sequence::pair;
+pub use nom::sequence::preceded;
+pub use nom::sequence::separated_pair;
+pub use nom::sequence::tuple;
+pub use nom::AsChar;
+pub use nom::IResult;
+
+pub use crate::answer::Answer;
+pub use crate::input::Input;
+pub use crate::parsers::ints;
+pub use crate::parsers::parse_i64;
+pub use crate::parsers::parse_int;
+pub use crate::parsers::parse_u64;
+pub use crate::parsers::parse_usize;
+pub use crate::parsers::take_after;
+pub use crate::parsers::{finish, parse_all, parse_lines, ParseError};
+pub use crate::parsers::{lines_of, parse_each_section, parse_sections, sections, template};
+pub use aoc_derive::AocParse;
+
+pub(crate) type ParseResult<'a, T> = IResult<&'a str, T>;
+
+pub mod combinatorics;
+pub mod graph;
+pub mod grid;
+pub mod line;
+pub mod math;
+pub mod point;
+pub mod range;
+pub mod search;
+pub mod simulate;
+pub mod tsp;
+
+pub use graph::{Graph, NodeRegistration};
+pub use grid::{Coord, Grid};
+pub use line::{Intersection, LineSegment};
+pub use point::{Direction, Point2, Point3};
+pub use range::{Cuboid, CuboidSet, RangeSet};
+pub use simulate::{simulate, Cycle, Simulation};
+
+pub fn stdio_string() -> std::string::String {
+    let mut s = String::new();
+    std::io::stdin()
+        .read_to_string(&mut s)
+        .expect("Failed to read to string");
+    s
+}
+
+#[macro_export]
+macro_rules! chain {
+    [ $x:expr ] => {
+        $x
+    };
+    [ $first:expr, $($x:expr),+ ] => {
+        $first.chain(chain![ $($x),+ ])
+    };
+}
+
+static VERBOSE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Turns on the tracing printed by `debug_log!`, off by default.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, std::sync::atomic::Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// `eprintln!` that only prints when running with `--verbose`.
+#[macro_export]
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        if $crate::prelude::is_verbose() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub fn sum_to_1(n: i64) -> i64 {
+    (n + 1) * n / 2
+}
+
+pub fn eprint_and_return<T>(x: T) -> T
+where
+    T: Debug,
+{
+    eprintln!("{:?}", x);
+    x
+}
+
+pub type ParserResult<'a, T> = IResult<&'a str, T>;
@@ -0,0 +1,360 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::grid::Coord;
+
+/// A point or vector on the integer plane, with `y` growing to the south like grid rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The vector with each component replaced by its sign, i.e. one step towards it.
+    pub fn signum(&self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Moves one step, straight or diagonally, towards `target` unless already there.
+    pub fn step_towards(&self, target: Point2) -> Point2 {
+        *self + (target - *self).signum()
+    }
+
+    pub fn transposed(&self) -> Point2 {
+        Point2::new(self.y, self.x)
+    }
+
+    /// Turns the vector a quarter counter-clockwise around the origin, as drawn with
+    /// `y` pointing down.
+    pub fn rotate_left(&self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// Turns the vector a quarter clockwise around the origin, as drawn with `y`
+    /// pointing down.
+    pub fn rotate_right(&self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// The four orthogonal neighbors.
+    pub fn neighbors4(&self) -> impl Iterator<Item = Point2> {
+        let p = *self;
+        Direction::ALL.into_iter().map(move |d| p + d.offset())
+    }
+
+    /// The four orthogonal and four diagonal neighbors.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Point2> {
+        let p = *self;
+        itertools::iproduct!(-1..=1, -1..=1)
+            .filter(|&offset| offset != (0, 0))
+            .map(move |(dx, dy)| p + Point2::new(dx, dy))
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+/// Grid coordinates map to `x = col` and `y = row`.
+impl From<Coord> for Point2 {
+    fn from(coord: Coord) -> Self {
+        Point2::new(coord.col as i64, coord.row as i64)
+    }
+}
+
+impl TryFrom<Point2> for Coord {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(p: Point2) -> Result<Self, Self::Error> {
+        Ok(Coord::new(p.y.try_into()?, p.x.try_into()?))
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A point or vector in integer space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(&self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn step_towards(&self, target: Point3) -> Point3 {
+        *self + (target - *self).signum()
+    }
+
+    /// Turns a quarter counter-clockwise around the x axis, looking from positive x.
+    pub fn rotate_x(&self) -> Point3 {
+        Point3::new(self.x, -self.z, self.y)
+    }
+
+    /// Turns a quarter counter-clockwise around the y axis, looking from positive y.
+    pub fn rotate_y(&self) -> Point3 {
+        Point3::new(self.z, self.y, -self.x)
+    }
+
+    /// Turns a quarter counter-clockwise around the z axis, looking from positive z.
+    pub fn rotate_z(&self) -> Point3 {
+        Point3::new(-self.y, self.x, self.z)
+    }
+
+    /// The 24 orientations reachable by quarter turns, starting with the point itself.
+    pub fn rotations(&self) -> Vec<Point3> {
+        // Point each of the six faces up the z axis, then spin around it.
+        let faces = [
+            *self,
+            self.rotate_x(),
+            self.rotate_x().rotate_x(),
+            self.rotate_x().rotate_x().rotate_x(),
+            self.rotate_y(),
+            self.rotate_y().rotate_y().rotate_y(),
+        ];
+        faces
+            .into_iter()
+            .flat_map(|face| std::iter::successors(Some(face), |p| Some(p.rotate_z())).take(4))
+            .collect()
+    }
+
+    /// The six neighbors sharing a face.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Point3> {
+        let p = *self;
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| p + offset)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($t:ident { $($field:ident),* }) => {
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, other: $t) -> $t {
+                $t { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, other: $t) -> $t {
+                $t { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Mul<i64> for $t {
+            type Output = $t;
+
+            fn mul(self, k: i64) -> $t {
+                $t { $($field: self.$field * k),* }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> $t {
+                $t { $($field: -self.$field),* }
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, other: $t) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+/// A compass direction, with north along negative `y`, i.e. up on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Direction {
+    /// Clockwise, starting from north.
+    pub const ALL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// One step in this direction.
+    pub fn offset(&self) -> Point2 {
+        match self {
+            Direction::N => Point2::new(0, -1),
+            Direction::E => Point2::new(1, 0),
+            Direction::S => Point2::new(0, 1),
+            Direction::W => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        Direction::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn opposite(&self) -> Direction {
+        Direction::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+/// Reads `N`/`E`/`S`/`W` as well as `U`/`R`/`D`/`L`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'N' | 'U' => Ok(Direction::N),
+            'E' | 'R' => Ok(Direction::E),
+            'S' | 'D' => Ok(Direction::S),
+            'W' | 'L' => Ok(Direction::W),
+            _ => Err(format!("{:?} is not a direction", c)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("{:?} is not a direction", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_distances() {
+        let (a, b) = (Point2::new(1, -2), Point2::new(4, 2));
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(a * 3, Point2::new(3, -6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.step_towards(b), Point2::new(2, -1));
+        assert_eq!(b.step_towards(b), b);
+    }
+
+    #[test]
+    fn turns() {
+        let p = Point2::new(2, 1);
+        assert_eq!(p.rotate_left(), Point2::new(1, -2));
+        assert_eq!(p.rotate_left().rotate_right(), p);
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::E.opposite(), Direction::W);
+        assert_eq!(Direction::N.offset().rotate_right(), Direction::E.offset());
+        assert_eq!(Direction::E.offset().rotate_left(), Direction::N.offset());
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parsed: Vec<Direction> = "URDLNESW".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(parsed[..4], Direction::ALL);
+        assert_eq!(parsed[4..], Direction::ALL);
+        assert_eq!("L".parse(), Ok(Direction::W));
+        assert!("X".parse::<Direction>().is_err());
+        assert!("NE".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn finds_all_24_rotations() {
+        let p = Point3::new(1, 2, 3);
+        let rotations: std::collections::HashSet<_> = p.rotations().into_iter().collect();
+        assert_eq!(rotations.len(), 24);
+        assert!(rotations.iter().all(|r| r.manhattan(Point3::ORIGIN) == 6));
+    }
+
+    #[test]
+    fn converts_grid_coordinates() {
+        let coord = Coord::new(3, 5);
+        assert_eq!(Point2::from(coord), Point2::new(5, 3));
+        assert_eq!(Coord::try_from(Point2::new(5, 3)), Ok(coord));
+        assert!(Coord::try_from(Point2::new(-1, 3)).is_err());
+        let below = Point2::from(coord) + Direction::S.offset();
+        assert_eq!(Coord::try_from(below), Ok(Coord::new(4, 5)));
+    }
+}
@@ -0,0 +1,298 @@
+//! Sets of integers kept as disjoint half-open ranges, and their higher-dimensional
+//! counterpart built from axis-aligned cuboids.
+
+use std::ops::Range;
+
+use itertools::Itertools;
+
+/// A set of integers stored as sorted, disjoint, non-adjacent half-open ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    /// The ranges making up the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        // The first range ending after `x` is the only one that can hold it.
+        let i = self.ranges.partition_point(|r| r.end <= x);
+        self.ranges.get(i).is_some_and(|r| r.start <= x)
+    }
+
+    /// Adds every integer in `range`, merging it with the ranges it touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            let (head, tail) = (&self.ranges[first], &self.ranges[last - 1]);
+            head.start.min(range.start)..tail.end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Takes every integer in `range` out of the set.
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+        // Only the outermost ranges can stick out past `range`.
+        let (head, tail) = (&self.ranges[first], &self.ranges[last - 1]);
+        let remainder = [head.start..range.start, range.end..tail.end]
+            .into_iter()
+            .filter(|r| !r.is_empty())
+            .collect_vec();
+        self.ranges.splice(first..last, remainder);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut set = self.clone();
+        for range in &other.ranges {
+            set.insert(range.clone());
+        }
+        set
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut set = self.clone();
+        for range in &other.ranges {
+            set.remove(range.clone());
+        }
+        set
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+/// Collects ranges in any order, overlapping or not.
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// An axis-aligned box in `N` dimensions, half-open along every axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub axes: [Range<i64>; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(axes: [Range<i64>; N]) -> Cuboid<N> {
+        Cuboid { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Range::is_empty)
+    }
+
+    /// The number of integer points inside.
+    pub fn volume(&self) -> i64 {
+        if self.is_empty() {
+            return 0;
+        }
+        self.axes.iter().map(|r| r.end - r.start).product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.axes.iter().zip(point).all(|(r, x)| r.contains(&x))
+    }
+
+    pub fn intersection(&self, other: &Cuboid<N>) -> Option<Cuboid<N>> {
+        let axes = std::array::from_fn(|i| {
+            self.axes[i].start.max(other.axes[i].start)..self.axes[i].end.min(other.axes[i].end)
+        });
+        let cuboid = Cuboid { axes };
+        (!cuboid.is_empty()).then_some(cuboid)
+    }
+
+    /// Cuts `other` out of this cuboid, leaving at most `2 * N` disjoint pieces.
+    pub fn difference(&self, other: &Cuboid<N>) -> Vec<Cuboid<N>> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![self.clone()];
+        };
+        // Slice off the parts below and above the overlap one axis at a time, then keep
+        // only the overlapping slab for the remaining axes.
+        let mut pieces = vec![];
+        let mut rest = self.clone();
+        for axis in 0..N {
+            let (inner, outer) = (&overlap.axes[axis], rest.axes[axis].clone());
+            for side in [outer.start..inner.start, inner.end..outer.end] {
+                if !side.is_empty() {
+                    let mut piece = rest.clone();
+                    piece.axes[axis] = side;
+                    pieces.push(piece);
+                }
+            }
+            rest.axes[axis] = inner.clone();
+        }
+        pieces
+    }
+}
+
+/// A set of integer points stored as disjoint cuboids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CuboidSet<const N: usize> {
+    cuboids: Vec<Cuboid<N>>,
+}
+
+impl<const N: usize> Default for CuboidSet<N> {
+    fn default() -> Self {
+        CuboidSet { cuboids: vec![] }
+    }
+}
+
+impl<const N: usize> CuboidSet<N> {
+    pub fn new() -> CuboidSet<N> {
+        CuboidSet::default()
+    }
+
+    pub fn cuboids(&self) -> &[Cuboid<N>] {
+        &self.cuboids
+    }
+
+    /// The number of points in the set.
+    pub fn volume(&self) -> i64 {
+        self.cuboids.iter().map(Cuboid::volume).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cuboids.is_empty()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.cuboids.iter().any(|c| c.contains(point))
+    }
+
+    pub fn insert(&mut self, cuboid: Cuboid<N>) {
+        if cuboid.is_empty() {
+            return;
+        }
+        self.remove(&cuboid);
+        self.cuboids.push(cuboid);
+    }
+
+    pub fn remove(&mut self, cuboid: &Cuboid<N>) {
+        self.cuboids = self
+            .cuboids
+            .iter()
+            .flat_map(|c| c.difference(cuboid))
+            .collect();
+    }
+
+    /// The part of the set inside `bounds`.
+    pub fn clip(&self, bounds: &Cuboid<N>) -> CuboidSet<N> {
+        CuboidSet {
+            cuboids: self
+                .cuboids
+                .iter()
+                .filter_map(|c| c.intersection(bounds))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_ranges() {
+        let set: RangeSet = [5..8, 0..2, 1..3, 3..4].into_iter().collect();
+        assert_eq!(set.ranges(), [0..4, 5..8]);
+        assert_eq!(set.len(), 7);
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(!set.contains(8));
+
+        let mut set = set;
+        set.insert(4..5);
+        assert_eq!(set, RangeSet::from(0..8));
+        set.remove(2..3);
+        set.remove(6..10);
+        assert_eq!(set.ranges(), [0..2, 3..6]);
+    }
+
+    #[test]
+    fn combines_range_sets() {
+        let a: RangeSet = [0..10, 20..30].into_iter().collect();
+        let b = RangeSet::from(5..25);
+        assert_eq!(a.union(&b), RangeSet::from(0..30));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..30]);
+        assert_eq!(b.difference(&a), RangeSet::from(10..20));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn subtracts_cuboids() {
+        let outer = Cuboid::new([0..3, 0..3, 0..3]);
+        let inner = Cuboid::new([1..2, 1..2, 1..2]);
+        let shell = outer.difference(&inner);
+        assert_eq!(shell.len(), 6);
+        assert_eq!(shell.iter().map(Cuboid::volume).sum::<i64>(), 26);
+
+        let mut set = CuboidSet::new();
+        set.insert(Cuboid::new([0..2, 0..2]));
+        set.insert(Cuboid::new([1..3, 1..3]));
+        assert_eq!(set.volume(), 7);
+        set.remove(&Cuboid::new([1..2, 0..3]));
+        assert_eq!(set.volume(), 4);
+        assert!(!set.contains([1, 1]));
+        assert!(set.contains([2, 2]));
+        assert_eq!(set.clip(&Cuboid::new([0..1, 0..5])).volume(), 2);
+    }
+}
@@ -0,0 +1,212 @@
+//! Shortest paths over implicit graphs, given as closures.
+//!
+//! Every search takes any number of start nodes and a goal predicate. It stops as soon
+//! as a goal is reached; pass `|_| false` to explore everything reachable instead.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the distance to each reached node and how it was reached.
+///
+/// When the search stopped at a goal, distances to nodes still waiting in the queue
+/// are upper bounds; the distance and path to the goal itself are exact.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Search<N, C> {
+        Search {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// The first goal the search reached.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The nodes from a start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search, where every edge costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let distance = search.distances[&node] + 1;
+        for next in neighbors(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm: `neighbors` yields each next node with the cost to get there.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    // The heap only holds indices into `queued`, so that nodes need not be `Ord`.
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), C::default());
+            heap.push((Reverse(heuristic(&start)), queued.len()));
+            queued.push((start, C::default()));
+        }
+    }
+
+    while let Some((_, i)) = heap.pop() {
+        let (node, distance) = queued[i].clone();
+        if search.distances[&node] < distance {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (next, cost) in neighbors(&node) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_distance)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_distance);
+            search.parents.insert(next.clone(), node.clone());
+            heap.push((Reverse(next_distance + heuristic(&next)), queued.len()));
+            queued.push((next, next_distance));
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 costs 3, 0 -> 3 directly costs 10, 4 is unreachable.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            4 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_edges() {
+        let search = bfs([0], |n| edges(n).into_iter().map(|(n, _)| n), |_| false);
+        assert_eq!(search.distance(&3), Some(1));
+        assert_eq!(search.distance(&2), Some(2));
+        assert_eq!(search.distance(&4), None);
+        assert_eq!(search.path(&2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let search = dijkstra([0], edges, |&n| n == 3);
+        assert_eq!(search.goal(), Some(&3));
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn searches_from_several_starts() {
+        let search = dijkstra([4, 2], edges, |_| false);
+        assert_eq!(search.distance(&3), Some(1));
+        assert_eq!(search.distance(&1), Some(2));
+        assert_eq!(search.path(&1), Some(vec![4, 0, 1]));
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let heuristic = |&n: &u32| 3u32.saturating_sub(n);
+        let search = astar([0], edges, heuristic, |&n| n == 3);
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2, 3]));
+    }
+}
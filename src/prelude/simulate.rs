@@ -0,0 +1,89 @@
+//! Running a step function many times, skipping ahead once the states repeat.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states from `start` on repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step showing the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation<S> {
+    /// The state after the requested number of steps.
+    pub state: S,
+    /// The cycle, if one showed up before the last step.
+    pub cycle: Option<Cycle>,
+}
+
+/// Applies `step` to `initial` `steps` times. Every state is remembered, so as soon as
+/// one comes back the rest of the run is looked up instead of computed.
+pub fn simulate<S, F>(initial: S, steps: usize, mut step: F) -> Simulation<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut history = vec![initial];
+    for n in 1..=steps {
+        let next = step(history.last().unwrap());
+        if let Some(&start) = seen.get(&next) {
+            let cycle = Cycle {
+                start,
+                length: n - start,
+            };
+            return Simulation {
+                state: history.swap_remove(cycle.reduce(steps)),
+                cycle: Some(cycle),
+            };
+        }
+        seen.insert(next.clone(), n);
+        history.push(next);
+    }
+    Simulation {
+        state: history.pop().unwrap(),
+        cycle: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_short_of_the_cycle() {
+        let simulation = simulate(0, 10, |n| n + 1);
+        assert_eq!(simulation.state, 10);
+        assert_eq!(simulation.cycle, None);
+    }
+
+    #[test]
+    fn skips_ahead() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let step = |&n: &u32| if n == 4 { 2 } else { n + 1 };
+        let simulation = simulate(0, 1_000_000_000, step);
+        assert_eq!(
+            simulation.cycle,
+            Some(Cycle {
+                start: 2,
+                length: 3
+            })
+        );
+        assert_eq!(simulation.state, 4);
+        assert_eq!(simulate(0, 4, step).state, 4);
+        assert_eq!(simulate(0, 5, step).state, 2);
+    }
+}
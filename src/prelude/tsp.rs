@@ -0,0 +1,171 @@
+//! Travelling-salesman style tours by the Held–Karp bitmask dynamic program.
+//!
+//! Distances are given as a matrix where `matrix[from][to]` is `None` for a missing
+//! edge. Time is `O(2^n * n^2)`, which is fine up to around twenty nodes.
+
+use std::ops::Add;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Shortest,
+    Longest,
+}
+
+impl Objective {
+    fn prefers<W: Ord>(&self, candidate: W, best: W) -> bool {
+        match self {
+            Objective::Shortest => candidate < best,
+            Objective::Longest => candidate > best,
+        }
+    }
+}
+
+/// A route visiting every node exactly once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour<W> {
+    pub cost: W,
+    /// The nodes in visiting order. For a cycle the return to the first node is implied.
+    pub order: Vec<usize>,
+}
+
+/// The best path visiting every node once, starting and ending anywhere.
+pub fn hamiltonian_path<W>(matrix: &[Vec<Option<W>>], objective: Objective) -> Option<Tour<W>>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    held_karp(matrix, objective, false)
+}
+
+/// The best cycle visiting every node once and returning to the start.
+pub fn hamiltonian_cycle<W>(matrix: &[Vec<Option<W>>], objective: Objective) -> Option<Tour<W>>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    held_karp(matrix, objective, true)
+}
+
+/// The best cost of a partial route and the node visited before its last one.
+type Step<W> = (W, Option<usize>);
+
+fn held_karp<W>(matrix: &[Vec<Option<W>>], objective: Objective, cycle: bool) -> Option<Tour<W>>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let n = matrix.len();
+    if n == 0 {
+        return None;
+    }
+    let full = (1usize << n) - 1;
+    // best[mask][last]: the best cost of visiting exactly `mask`, ending at `last`, along
+    // with the node visited before `last`.
+    let mut best: Vec<Vec<Option<Step<W>>>> = vec![vec![None; n]; 1 << n];
+    // A cycle can start anywhere, so pin it to node 0.
+    let starts = if cycle { 0..1 } else { 0..n };
+    for start in starts {
+        best[1 << start][start] = Some((W::default(), None));
+    }
+
+    for mask in 1..=full {
+        for last in 0..n {
+            let Some((cost, _)) = best[mask][last] else {
+                continue;
+            };
+            for next in (0..n).filter(|next| mask & (1 << next) == 0) {
+                let Some(step) = matrix[last][next] else {
+                    continue;
+                };
+                let candidate = cost + step;
+                let entry = &mut best[mask | (1 << next)][next];
+                if entry.is_none_or(|(known, _)| objective.prefers(candidate, known)) {
+                    *entry = Some((candidate, Some(last)));
+                }
+            }
+        }
+    }
+
+    let mut finish: Option<(W, usize)> = None;
+    for last in 0..n {
+        let Some((cost, _)) = best[full][last] else {
+            continue;
+        };
+        let cost = if cycle && n > 1 {
+            let Some(back) = matrix[last][0] else {
+                continue;
+            };
+            cost + back
+        } else {
+            cost
+        };
+        if finish.is_none_or(|(known, _)| objective.prefers(cost, known)) {
+            finish = Some((cost, last));
+        }
+    }
+
+    let (cost, mut last) = finish?;
+    let mut mask = full;
+    let mut order = vec![last];
+    while let Some((_, Some(previous))) = best[mask][last] {
+        mask &= !(1 << last);
+        last = previous;
+        order.push(last);
+    }
+    order.reverse();
+    Some(Tour { cost, order })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symmetric(n: usize, edges: &[(usize, usize, u32)]) -> Vec<Vec<Option<u32>>> {
+        let mut matrix = vec![vec![None; n]; n];
+        for &(a, b, w) in edges {
+            matrix[a][b] = Some(w);
+            matrix[b][a] = Some(w);
+        }
+        matrix
+    }
+
+    #[test]
+    fn finds_shortest_and_longest_paths() {
+        // London, Dublin, Belfast.
+        let matrix = symmetric(3, &[(0, 1, 464), (0, 2, 518), (1, 2, 141)]);
+        let shortest = hamiltonian_path(&matrix, Objective::Shortest).unwrap();
+        assert_eq!(shortest.cost, 605);
+        assert!(shortest.order == [0, 1, 2] || shortest.order == [2, 1, 0]);
+        let longest = hamiltonian_path(&matrix, Objective::Longest).unwrap();
+        assert_eq!(longest.cost, 982);
+        assert!(longest.order == [1, 0, 2] || longest.order == [2, 0, 1]);
+    }
+
+    #[test]
+    fn finds_cycles() {
+        // A square with expensive diagonals.
+        let matrix = symmetric(
+            4,
+            &[
+                (0, 1, 1),
+                (1, 2, 1),
+                (2, 3, 1),
+                (3, 0, 1),
+                (0, 2, 5),
+                (1, 3, 5),
+            ],
+        );
+        let shortest = hamiltonian_cycle(&matrix, Objective::Shortest).unwrap();
+        assert_eq!(shortest.cost, 4);
+        assert_eq!(shortest.order[0], 0);
+        assert_eq!(shortest.order.len(), 4);
+        assert_eq!(
+            hamiltonian_cycle(&matrix, Objective::Longest).unwrap().cost,
+            12
+        );
+
+        let line = symmetric(3, &[(0, 1, 1), (1, 2, 1)]);
+        assert_eq!(hamiltonian_cycle(&line, Objective::Shortest), None);
+        assert_eq!(
+            hamiltonian_path(&line, Objective::Shortest).unwrap().cost,
+            2
+        );
+    }
+}
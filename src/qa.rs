@@ -0,0 +1,187 @@
+use std::path::Path;
+
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+
+use crate::answer::Answer;
+
+/// One row of the `QA` table: a puzzle input and, once known, its answer.
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub year: u32,
+    pub day: usize,
+    pub part: usize,
+    pub question: String,
+    pub answer: Option<String>,
+}
+
+/// An answer that was submitted to the server, with the verdict it got.
+#[derive(Debug, Clone)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: String,
+}
+
+/// A SQLite database of questions and answers, as used by `tests/qa.sqlite` and
+/// `inputs/qa.sqlite`.
+pub struct QaDb {
+    conn: Connection,
+}
+
+impl QaDb {
+    /// Opens an existing database without changing its schema.
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<QaDb> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_WRITE)?;
+        Ok(QaDb { conn })
+    }
+
+    /// Opens the database for writing cases, creating it and its `QA` table if needed.
+    pub fn create(path: impl AsRef<Path>) -> rusqlite::Result<QaDb> {
+        let conn = Connection::open(path)?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS QA (
+                year INTEGER,
+                day INTEGER,
+                part INTEGER,
+                question TEXT,
+                answer TEXT
+            )",
+            [],
+        )?;
+        Ok(QaDb { conn })
+    }
+
+    /// Creates the `Attempts` table if needed, for recording submissions.
+    pub fn create_attempts(&self) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS Attempts (
+                year INTEGER,
+                day INTEGER,
+                part INTEGER,
+                answer TEXT,
+                verdict TEXT,
+                time INTEGER
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+
+    pub fn add(
+        &self,
+        year: u32,
+        day: usize,
+        part: usize,
+        question: &str,
+        answer: Option<&str>,
+    ) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT INTO QA (year, day, part, question, answer) VALUES (?, ?, ?, ?, ?)",
+            (year, day, part, question, answer),
+        )?;
+        Ok(())
+    }
+
+    /// Lists the cases matching every filter that is given.
+    pub fn cases(
+        &self,
+        year: Option<u32>,
+        day: Option<usize>,
+        part: Option<usize>,
+    ) -> rusqlite::Result<Vec<Case>> {
+        let mut statement = self.conn.prepare(
+            "SELECT year, day, part, question, answer
+             FROM QA
+             WHERE (?1 IS NULL OR year = ?1)
+                AND (?2 IS NULL OR day = ?2)
+                AND (?3 IS NULL OR part = ?3)
+             ORDER BY year, day, part, question",
+        )?;
+        let cases = statement
+            .query_map((year, day, part), |row| {
+                Ok(Case {
+                    year: row.get(0)?,
+                    day: row.get(1)?,
+                    part: row.get(2)?,
+                    question: row.get(3)?,
+                    answer: row.get(4)?,
+                })
+            })?
+            .collect();
+        cases
+    }
+
+    /// The first question stored for a puzzle part.
+    pub fn question(&self, year: u32, day: usize, part: usize) -> rusqlite::Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT question FROM QA WHERE year = ? AND day = ? AND part = ?",
+                (year, day, part),
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// Sets the answer of a case, returning whether a row was updated.
+    pub fn save_answer(&self, case: &Case, answer: &str) -> rusqlite::Result<bool> {
+        let updated = self.set_answer(case.year, case.day, case.part, &case.question, answer)?;
+        Ok(updated > 0)
+    }
+
+    /// Sets the answer of every case of a puzzle part with this question.
+    pub fn set_answer(
+        &self,
+        year: u32,
+        day: usize,
+        part: usize,
+        question: &str,
+        answer: &str,
+    ) -> rusqlite::Result<usize> {
+        self.conn.execute(
+            "UPDATE QA SET answer = ?
+             WHERE year = ? AND day = ? AND part = ? AND question = ?",
+            (answer, year, day, part, question),
+        )
+    }
+
+    pub fn record_attempt(
+        &self,
+        year: u32,
+        day: usize,
+        part: usize,
+        answer: &str,
+        verdict: &str,
+    ) -> rusqlite::Result<()> {
+        let time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.conn.execute(
+            "INSERT INTO Attempts (year, day, part, answer, verdict, time)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            (year, day, part, answer, verdict, time),
+        )?;
+        Ok(())
+    }
+
+    /// The answers submitted for a puzzle part, oldest first.
+    pub fn attempts(&self, year: u32, day: usize, part: usize) -> rusqlite::Result<Vec<Attempt>> {
+        let mut statement = self.conn.prepare(
+            "SELECT answer, verdict FROM Attempts
+             WHERE year = ? AND day = ? AND part = ?
+             ORDER BY time, rowid",
+        )?;
+        let attempts = statement
+            .query_map((year, day, part), |row| {
+                Ok(Attempt {
+                    answer: row.get(0)?,
+                    verdict: row.get(1)?,
+                })
+            })?
+            .collect();
+        attempts
+    }
+}
+
+/// An answer as it is stored in the `answer` column, without trailing newlines.
+pub fn format_answer(answer: &Answer) -> String {
+    answer.to_string().trim_end_matches('\n').to_string()
+}
@@ -0,0 +1,110 @@
+use crate::answer::Solution;
+use crate::{aoc_2015, aoc_2021, aoc_2022};
+
+pub const YEARS: [u32; 3] = [2015, 2021, 2022];
+pub const DAYS: usize = 25;
+pub const PARTS: usize = 2;
+
+/// Whether a puzzle part can be run.
+#[derive(Debug, Clone, Copy)]
+pub enum Status {
+    Implemented(Solution),
+    /// The day is registered, but this part has no solution yet.
+    Unimplemented,
+    /// The year, day or part is not registered at all.
+    Missing,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Implemented(_) => "implemented",
+            Status::Unimplemented => "unimplemented",
+            Status::Missing => "missing",
+        }
+    }
+}
+
+fn solutions(year: u32) -> Option<&'static [[Option<Solution>; PARTS]]> {
+    match year {
+        2015 => Some(&aoc_2015::SOLUTIONS),
+        2021 => Some(&aoc_2021::SOLUTIONS),
+        2022 => Some(aoc_2022::SOLUTIONS),
+        _ => None,
+    }
+}
+
+pub fn status(year: u32, day: usize, part: usize) -> Status {
+    let entry = solutions(year)
+        .and_then(|s| s.get(day.checked_sub(1)?))
+        .and_then(|d| d.get(part.checked_sub(1)?));
+    match entry {
+        Some(Some(solution)) => Status::Implemented(*solution),
+        Some(None) => Status::Unimplemented,
+        None => Status::Missing,
+    }
+}
+
+/// Looks up the solution for a puzzle part, if it is implemented.
+pub fn solution(year: u32, day: usize, part: usize) -> Option<Solution> {
+    match status(year, day, part) {
+        Status::Implemented(solution) => Some(solution),
+        _ => None,
+    }
+}
+
+/// Every registered `(year, day, part)` in order, implemented or not.
+pub fn puzzles() -> impl Iterator<Item = (u32, usize, usize)> {
+    YEARS.into_iter().flat_map(|year| {
+        let days = solutions(year).map_or(0, |s| s.len());
+        (1..=days).flat_map(move |day| (1..=PARTS).map(move |part| (year, day, part)))
+    })
+}
+
+/// Checks that `day` is one of the `DAYS` days of a year.
+pub fn check_day(day: usize) -> Result<(), String> {
+    if (1..=DAYS).contains(&day) {
+        Ok(())
+    } else {
+        Err(format!("day must be between 1 and {}, got {}", DAYS, day))
+    }
+}
+
+/// Looks up the solution for a puzzle part, or explains why it cannot be run.
+pub fn lookup(year: u32, day: usize, part: usize) -> Result<Solution, String> {
+    match status(year, day, part) {
+        Status::Implemented(solution) => Ok(solution),
+        Status::Unimplemented => Err(format!(
+            "{} day {} part {} is not implemented yet",
+            year, day, part
+        )),
+        Status::Missing => Err(match (solutions(year), check_day(day)) {
+            (None, _) => format!(
+                "{} is not a registered year (expected one of {})",
+                year,
+                YEARS.map(|y| y.to_string()).join(", ")
+            ),
+            (Some(_), Err(message)) => message,
+            (Some(_), Ok(())) if !(1..=PARTS).contains(&part) => {
+                format!("part must be between 1 and {}, got {}", PARTS, part)
+            }
+            (Some(days), Ok(())) => format!(
+                "{} day {} is not registered ({} has days 1 to {})",
+                year,
+                day,
+                year,
+                days.len()
+            ),
+        }),
+    }
+}
+
+/// Like [`lookup`], but for running a puzzle's example: a few puzzles use different
+/// parameters for their example than for the real input.
+pub fn lookup_example(year: u32, day: usize, part: usize) -> Result<Solution, String> {
+    match (year, day, part) {
+        (2022, 15, 1) => Ok(|input| aoc_2022::day15::part1_at(input, 10)),
+        (2022, 15, 2) => Ok(|input| aoc_2022::day15::part2_at(input, 20)),
+        _ => lookup(year, day, part),
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::answer::{Answer, Solution};
+use crate::input::Input;
+use crate::parsers::ParseError;
+
+/// Why a solution did not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The input did not have the expected shape.
+    Parse(ParseError),
+    /// The solution panicked with this message.
+    Panic(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Parse(e) => write!(f, "{}", e),
+            Failure::Panic(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<Failure> for String {
+    fn from(failure: Failure) -> Self {
+        failure.to_string()
+    }
+}
+
+/// The outcome of running one solution on one input.
+pub struct Run {
+    pub answer: Result<Answer, Failure>,
+    pub elapsed: Duration,
+}
+
+/// Runs the solution for a puzzle, turning a panic or a [`ParseError`] into a [`Failure`]
+/// instead of aborting.
+pub fn run(year: u32, day: usize, solution: Solution, input: &str) -> Run {
+    let input = Input::from(input);
+    let start = Instant::now();
+    let answer = match catch_unwind(AssertUnwindSafe(|| solution(&input))) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(error)) => Err(Failure::Parse(error.with_puzzle(year, day))),
+        Err(payload) => Err(Failure::Panic(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| String::from("panicked")),
+        )),
+    };
+    let elapsed = start.elapsed();
+    Run { answer, elapsed }
+}
+
+/// What happened to one puzzle part, as reported by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: usize,
+    pub part: usize,
+    pub answer: Option<String>,
+    pub elapsed_ns: Option<u128>,
+    /// `"ok"`, `"failed"`, `"unimplemented"`, `"missing"` or `"no input"`.
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// A record for a puzzle part that was not run.
+    pub fn skipped(year: u32, day: usize, part: usize, status: &'static str) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed_ns: None,
+            status,
+            error: None,
+        }
+    }
+
+    pub fn from_run(year: u32, day: usize, part: usize, run: Run) -> Record {
+        let (answer, status, error) = match run.answer {
+            Ok(answer) => (Some(answer.to_string()), "ok", None),
+            Err(failure) => (None, "failed", Some(failure.to_string())),
+        };
+        Record {
+            year,
+            day,
+            part,
+            answer,
+            elapsed_ns: Some(run.elapsed.as_nanos()),
+            status,
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{parse_lines, parse_u64};
+
+    fn sum(input: &Input) -> Result<Answer, ParseError> {
+        Ok(parse_lines(input, parse_u64)?.iter().sum::<u64>().into())
+    }
+
+    #[test]
+    fn runs_solutions() {
+        let run = run(2021, 1, sum, "1\n2\n");
+        assert_eq!(run.answer.unwrap().to_string(), "3");
+    }
+
+    #[test]
+    fn reports_parse_errors_with_the_puzzle() {
+        let Err(Failure::Parse(error)) = run(2021, 1, sum, "1\n2x\n").answer else {
+            panic!("expected a parse failure");
+        };
+        assert_eq!(error.puzzle, Some((2021, 1)));
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn reports_panics() {
+        let answer = run(2021, 1, |_| panic!("no answer"), "").answer;
+        assert_eq!(
+            answer.unwrap_err(),
+            Failure::Panic(String::from("no answer"))
+        );
+    }
+}
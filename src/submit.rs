@@ -0,0 +1,197 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::client::{Client, ClientError};
+use crate::qa::{Attempt, QaDb};
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    /// The part is locked, or was already solved.
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+            Verdict::WrongLevel => "wrong level",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::RateLimited,
+            Verdict::WrongLevel,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.name() == name)
+    }
+
+    /// Whether the verdict tells anything about the answer itself.
+    fn is_final(&self) -> bool {
+        !matches!(self, Verdict::RateLimited | Verdict::WrongLevel)
+    }
+}
+
+/// The server's verdict, with the message it came with.
+#[derive(Debug)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub message: String,
+    /// Whether a correct answer was saved to a case in the `QA` table.
+    pub saved: bool,
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answer was not sent, because earlier attempts show it cannot be right.
+    Refused(String),
+    Unrecognized(String),
+    Client(ClientError),
+    Db(rusqlite::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(reason) => write!(f, "Not submitting: {}", reason),
+            SubmitError::Unrecognized(message) => {
+                write!(f, "Could not understand the response: {}", message)
+            }
+            SubmitError::Client(e) => write!(f, "{}", e),
+            SubmitError::Db(e) => write!(f, "Failed to query the answer database: {}", e),
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(e: ClientError) -> Self {
+        SubmitError::Client(e)
+    }
+}
+
+impl From<rusqlite::Error> for SubmitError {
+    fn from(e: rusqlite::Error) -> Self {
+        SubmitError::Db(e)
+    }
+}
+
+/// The text of the `<article>` the server puts its verdict in, without markup.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().join(" ")
+}
+
+pub fn parse_response(page: &str) -> Result<Outcome, SubmitError> {
+    let message = article_text(page);
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err(SubmitError::Unrecognized(message));
+    };
+    Ok(Outcome {
+        verdict,
+        message,
+        saved: false,
+    })
+}
+
+/// Explains why earlier attempts rule out `answer`, if they do.
+pub fn check_attempts(attempts: &[Attempt], answer: &str) -> Result<(), SubmitError> {
+    let number = answer.parse::<i128>().ok();
+    for attempt in attempts {
+        let verdict = match Verdict::from_name(&attempt.verdict) {
+            Some(verdict) if verdict.is_final() => verdict,
+            _ => continue,
+        };
+        let previous = attempt.answer.parse::<i128>().ok();
+        let reason = match (verdict, number, previous) {
+            (Verdict::Correct, _, _) if attempt.answer == answer => {
+                format!("{} was already accepted", answer)
+            }
+            (Verdict::Correct, _, _) => {
+                format!("already solved with {}", attempt.answer)
+            }
+            _ if attempt.answer == answer => {
+                format!("{} was already rejected as {}", answer, verdict.name())
+            }
+            (Verdict::TooHigh, Some(n), Some(bound)) if n >= bound => {
+                format!("{} is too high, since {} already was", answer, bound)
+            }
+            (Verdict::TooLow, Some(n), Some(bound)) if n <= bound => {
+                format!("{} is too low, since {} already was", answer, bound)
+            }
+            _ => continue,
+        };
+        return Err(SubmitError::Refused(reason));
+    }
+    Ok(())
+}
+
+/// Submits an answer unless earlier attempts rule it out, and records the attempt.
+///
+/// A correct answer is also saved as the answer to `question` in the `QA` table.
+pub fn submit(
+    client: &mut Client,
+    db: &QaDb,
+    year: u32,
+    day: usize,
+    part: usize,
+    question: &str,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    check_attempts(&db.attempts(year, day, part)?, answer)?;
+    let mut outcome = match parse_response(&client.answer(year, day, part, answer)?) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            // The answer did reach the server, so it still counts as an attempt.
+            db.record_attempt(year, day, part, answer, "unrecognized")?;
+            return Err(e);
+        }
+    };
+    db.record_attempt(year, day, part, answer, outcome.verdict.name())?;
+    if outcome.verdict == Verdict::Correct {
+        outcome.saved = db.set_answer(year, day, part, question, answer)? > 0;
+    }
+    Ok(outcome)
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use aoc::client::{self, Client, ClientError, Fetched};
+use aoc::inputs::InputStore;
+use aoc::qa::QaDb;
+use common::{temp_dir, Request, Server};
+
+fn respond(request: &Request) -> (u16, String) {
+    if request.cookie.as_deref() != Some("session=secret") {
+        return (400, String::from("Puzzle inputs differ by user."));
+    }
+    match request.path.as_str() {
+        "/2022/day/1/input" => (200, String::from("1000\n2000\n")),
+        "/2022/day/2/input" => (200, String::from("A Y\n")),
+        _ => (404, String::from("Not Found")),
+    }
+}
+
+#[test]
+fn downloads_once_then_uses_the_cache() {
+    let server = Server::start(respond);
+    let dir = temp_dir("fetch-cache");
+    let store = InputStore::open(&dir).unwrap();
+    let mut client = Client::new(&server.url, "secret").interval(Duration::ZERO);
+
+    let fetched = client::fetch_input(&mut client, &store, 2022, 1).unwrap();
+    assert_eq!(fetched, Fetched::Downloaded);
+    assert_eq!(
+        store.get(2022, 1, 1).unwrap().as_deref(),
+        Some("1000\n2000\n")
+    );
+
+    let fetched = client::fetch_input(&mut client, &store, 2022, 1).unwrap();
+    assert_eq!(fetched, Fetched::Cached);
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(server.requests()[0].method, "GET");
+}
+
+#[test]
+fn records_fetched_inputs_as_questions() {
+    let server = Server::start(respond);
+    let dir = temp_dir("fetch-questions");
+    let store = InputStore::open(&dir).unwrap();
+    let mut client = Client::new(&server.url, "secret").interval(Duration::ZERO);
+    client::fetch_input(&mut client, &store, 2022, 1).unwrap();
+
+    let db = QaDb::open(dir.join("qa.sqlite")).unwrap();
+    assert_eq!(
+        db.set_answer(2022, 1, 1, "1000\n2000\n", "2000").unwrap(),
+        1
+    );
+    let cases = db.cases(Some(2022), Some(1), None).unwrap();
+    let answers: Vec<_> = cases
+        .iter()
+        .map(|c| (c.part, c.answer.as_deref()))
+        .collect();
+    assert_eq!(answers, [(1, Some("2000")), (2, None)]);
+    assert!(cases.iter().all(|c| c.question == "1000\n2000\n"));
+}
+
+#[test]
+fn reports_server_errors() {
+    let server = Server::start(respond);
+    let dir = temp_dir("fetch-errors");
+    let store = InputStore::open(&dir).unwrap();
+
+    let mut client = Client::new(&server.url, "secret").interval(Duration::ZERO);
+    let result = client::fetch_input(&mut client, &store, 2022, 25);
+    assert!(matches!(result, Err(ClientError::Status(404, _))));
+
+    let mut client = Client::new(&server.url, "wrong").interval(Duration::ZERO);
+    let result = client::fetch_input(&mut client, &store, 2022, 1);
+    assert!(matches!(result, Err(ClientError::Status(400, _))));
+    assert!(store.get(2022, 1, 1).unwrap().is_none());
+}
+
+#[test]
+fn waits_between_requests() {
+    let server = Server::start(respond);
+    let dir = temp_dir("fetch-rate");
+    let store = InputStore::open(&dir).unwrap();
+    let mut client = Client::new(&server.url, "secret").interval(Duration::from_millis(200));
+
+    let start = Instant::now();
+    client::fetch_input(&mut client, &store, 2022, 1).unwrap();
+    client::fetch_input(&mut client, &store, 2022, 2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use aoc::qa::{self, Case, QaDb};
+use aoc::{registry, runner};
+use libtest_mimic::{Arguments, Failed, Trial};
+use similar::TextDiff;
+
+fn check(case: &Case, expected: &str) -> Result<(), Failed> {
+    let solution = registry::lookup_example(case.year, case.day, case.part)?;
+    let run = runner::run(case.year, case.day, solution, &case.question);
+    let actual = qa::format_answer(&run.answer?);
+    if actual == expected {
+        return Ok(());
+    }
+    let expected = format!("{}\n", expected);
+    let actual = format!("{}\n", actual);
+    let diff = TextDiff::from_lines(&expected, &actual)
+        .unified_diff()
+        .header("expected", "actual")
+        .to_string();
+    Err(diff.into())
+}
+
+fn test_prefix(case: &Case) -> String {
+    format!("aoc_{}::day{}::part{}", case.year, case.day, case.part)
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/qa.sqlite");
+    let cases = QaDb::open(path)
+        .and_then(|db| db.cases(None, None, None))
+        .expect("Failed to load test cases");
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let trials = cases
+        .into_iter()
+        .map(|case| {
+            let prefix = test_prefix(&case);
+            let index = counts.entry(prefix.clone()).or_insert(0);
+            let name = format!("{}::case{}", prefix, index);
+            *index += 1;
+            match case.answer.clone() {
+                Some(answer) => Trial::test(name, move || check(&case, &answer)),
+                None => Trial::test(name, || Ok(())).with_ignored_flag(true),
+            }
+        })
+        .collect();
+
+    libtest_mimic::run(&args, trials).exit();
+}
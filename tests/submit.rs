@@ -0,0 +1,118 @@
+mod common;
+
+use std::time::Duration;
+
+use aoc::client::Client;
+use aoc::qa::QaDb;
+use aoc::submit::{self, SubmitError, Verdict};
+use common::{temp_dir, Request, Server};
+
+fn page(message: &str) -> String {
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        message
+    )
+}
+
+fn respond(request: &Request) -> (u16, String) {
+    if request.method != "POST" || request.path != "/2022/day/1/answer" {
+        return (404, String::from("Not Found"));
+    }
+    let message = match request.body.as_str() {
+        "level=1&answer=24000" => "That's the right answer! You are <em>one gold star</em> closer.",
+        "level=1&answer=30000" => "That's not the right answer; your answer is too high.",
+        "level=1&answer=100" => "That's not the right answer; your answer is too low.",
+        "level=1&answer=abc" => "That's not the right answer.",
+        "level=1&answer=777" => "You gave an answer too recently. You have 42s left to wait.",
+        "level=2&answer=1" => "You don't seem to be solving the right level.",
+        _ => "Something else entirely.",
+    };
+    (200, page(message))
+}
+
+fn setup(name: &str) -> (Server, Client, QaDb) {
+    let server = Server::start(respond);
+    let client = Client::new(&server.url, "secret").interval(Duration::ZERO);
+    let db = QaDb::create(temp_dir(name).join("qa.sqlite")).unwrap();
+    db.create_attempts().unwrap();
+    (server, client, db)
+}
+
+fn verdict(client: &mut Client, db: &QaDb, part: usize, answer: &str) -> Verdict {
+    submit::submit(client, db, 2022, 1, part, "input", answer)
+        .unwrap()
+        .verdict
+}
+
+#[test]
+fn parses_every_verdict() {
+    let (_server, mut client, db) = setup("submit-verdicts");
+    assert_eq!(verdict(&mut client, &db, 1, "777"), Verdict::RateLimited);
+    assert_eq!(verdict(&mut client, &db, 1, "30000"), Verdict::TooHigh);
+    assert_eq!(verdict(&mut client, &db, 1, "100"), Verdict::TooLow);
+    assert_eq!(verdict(&mut client, &db, 1, "abc"), Verdict::Wrong);
+    assert_eq!(verdict(&mut client, &db, 2, "1"), Verdict::WrongLevel);
+    assert_eq!(verdict(&mut client, &db, 1, "24000"), Verdict::Correct);
+
+    let result = submit::submit(&mut client, &db, 2022, 1, 2, "input", "2");
+    assert!(matches!(result, Err(SubmitError::Unrecognized(_))));
+    let last = db.attempts(2022, 1, 2).unwrap().pop().unwrap();
+    assert_eq!(
+        (last.answer.as_str(), last.verdict.as_str()),
+        ("2", "unrecognized")
+    );
+}
+
+#[test]
+fn records_attempts_and_the_correct_answer() {
+    let (_server, mut client, db) = setup("submit-record");
+    db.add(2022, 1, 1, "input", None).unwrap();
+    verdict(&mut client, &db, 1, "777");
+    let outcome = submit::submit(&mut client, &db, 2022, 1, 1, "input", "24000").unwrap();
+    assert!(outcome.saved);
+
+    let attempts = db.attempts(2022, 1, 1).unwrap();
+    let attempts: Vec<_> = attempts
+        .iter()
+        .map(|a| (a.answer.as_str(), a.verdict.as_str()))
+        .collect();
+    assert_eq!(attempts, [("777", "rate limited"), ("24000", "correct")]);
+    let cases = db.cases(Some(2022), Some(1), Some(1)).unwrap();
+    assert_eq!(cases[0].answer.as_deref(), Some("24000"));
+}
+
+#[test]
+fn refuses_answers_ruled_out_by_earlier_attempts() {
+    let (server, mut client, db) = setup("submit-refuse");
+    verdict(&mut client, &db, 1, "777");
+    verdict(&mut client, &db, 1, "30000");
+    verdict(&mut client, &db, 1, "100");
+    verdict(&mut client, &db, 1, "abc");
+    let sent = server.requests().len();
+
+    for answer in ["30000", "30001", "100", "99", "abc"] {
+        let result = submit::submit(&mut client, &db, 2022, 1, 1, "input", answer);
+        assert!(
+            matches!(result, Err(SubmitError::Refused(_))),
+            "{} was not refused",
+            answer
+        );
+    }
+    assert_eq!(server.requests().len(), sent);
+
+    assert_eq!(verdict(&mut client, &db, 1, "777"), Verdict::RateLimited);
+    assert_eq!(verdict(&mut client, &db, 1, "24000"), Verdict::Correct);
+    let result = submit::submit(&mut client, &db, 2022, 1, 1, "input", "24000");
+    assert!(matches!(result, Err(SubmitError::Refused(_))));
+}
+
+#[test]
+fn reports_answers_without_a_case_to_save_to() {
+    let (_server, mut client, db) = setup("submit-unsaved");
+    db.add(2022, 1, 1, "another input", None).unwrap();
+    let outcome = submit::submit(&mut client, &db, 2022, 1, 1, "input", "24000").unwrap();
+    assert_eq!(outcome.verdict, Verdict::Correct);
+    assert!(!outcome.saved);
+    let cases = db.cases(Some(2022), Some(1), Some(1)).unwrap();
+    assert_eq!(cases[0].answer, None);
+}